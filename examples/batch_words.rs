@@ -0,0 +1,39 @@
+//! Compares the allocations and time of converting batches of numbers with `u128_to_words` in a
+//! loop and with `u128_slice_write_words`.
+//!
+//! Run with `cargo run --release --example batch_words`.
+
+mod counting_alloc;
+
+use counting_alloc::{measure, CountingAllocator};
+use num2en::{u128_slice_write_words, u128_to_words};
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const BATCHES: u128 = 100;
+const BATCH_SIZE: u128 = 10_000;
+
+fn measure_batches(name: &str, mut convert: impl FnMut(&[u128])) {
+    let batches = (0..BATCHES)
+        .map(|batch| (0..BATCH_SIZE).map(|i| (batch * BATCH_SIZE + i) * 7_919).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    measure(name, || {
+        for batch in &batches {
+            convert(batch);
+        }
+    });
+}
+
+fn main() {
+    let mut words = Vec::new();
+    measure_batches("u128_to_words loop", |batch| {
+        words = batch.iter().map(|&n| u128_to_words(n)).collect();
+    });
+
+    let mut words = Vec::new();
+    measure_batches("u128_slice_write_words", |batch| {
+        u128_slice_write_words(batch, &mut words);
+    });
+}
@@ -0,0 +1,44 @@
+//! Compares the allocations and time of spelling numbers through the `Vec<String>` word groups
+//! and through `write_u128_words` into a reused `String`.
+//!
+//! Run with `cargo run --release --example write_words`.
+
+mod counting_alloc;
+
+use counting_alloc::{measure, CountingAllocator};
+use num2en::{u128_to_word_groups, u128_to_words, write_u128_words};
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const COUNT: u128 = 1_000_000;
+
+fn numbers() -> impl Iterator<Item = u128> {
+    (0..COUNT).map(|i| i * 1_000_003)
+}
+
+fn main() {
+    let mut total_len = 0;
+    measure("u128_to_word_groups + join", || {
+        for n in numbers() {
+            total_len += u128_to_word_groups(n).join(" ").len();
+        }
+    });
+
+    measure("u128_to_words", || {
+        for n in numbers() {
+            total_len += u128_to_words(n).len();
+        }
+    });
+
+    let mut words = String::new();
+    measure("write_u128_words", || {
+        for n in numbers() {
+            words.clear();
+            write_u128_words(n, &mut words);
+            total_len += words.len();
+        }
+    });
+
+    println!("{} bytes written", total_len);
+}
@@ -23,17 +23,19 @@
 //! assert_eq!( isize_to_words(2012), "two thousand twelve");
 //! ```
 //! 
-//! To convert any unsigned integer `n` of type `X` to ***ordinal*** number words, call
+//! To convert any integer `n` of type `X` to ***ordinal*** number words, call
 //! `X_to_ord_words(n)`.
 //! 
 //! ```rust
 //! # use num2en::*;
 //! assert_eq!(    u8_to_ord_words(1),    "first");
+//! assert_eq!(    i8_to_ord_words(-2),   "negative second");
 //! assert_eq!(   u16_to_ord_words(3),    "third");
 //! assert_eq!(   u32_to_ord_words(5),    "fifth");
 //! assert_eq!(   u64_to_ord_words(70),   "seventieth");
 //! assert_eq!(  u128_to_ord_words(180),  "one hundred eightieth");
 //! assert_eq!( usize_to_ord_words(2012), "two thousand twelfth");
+//! assert_eq!( isize_to_ord_words(-2012), "negative two thousand twelfth");
 //! ```
 //! 
 //! To convert any float `f` of type `Y` to number words, call `Y_to_words(f)`.
@@ -59,59 +61,259 @@
 //! assert_eq!( str_digits_to_words("001247"), Ok("zero zero one two four seven".to_string()) );
 //! ```
 //! 
+//! # `no_std`
+//! The crate only needs `alloc`. Disable the default `std` feature to use it in `no_std` builds;
+//! everything except `read_to_words` is available without it.
+//! 
 //! 
 //! This crate has been thoroughly tested, but if you find any function working incorrectly
 //! for some input, please [open an issue on Github](https://github.com/simon-sovic/num2en/issues/new).
 //!
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-fn lt1000(n: u16, words: &mut Vec<String>) {
-    let hundreds = n / 100;
-    if hundreds != 0 {
-        lt100(hundreds as u8, words);
-        words.push("hundred".to_string());
+extern crate alloc;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+pub mod roman;
+pub use roman::{u16_to_roman, u16_to_roman_lowercase, RomanError};
+
+
+/// Receives the words of a number from [sink_lt1000] and [sink_lt100], so the sub-thousand logic is
+/// shared by the functions building a [`Vec`]`<`[`String`]`>` and the ones writing into a [`String`].
+trait WordSink {
+    /// Receives a single word.
+    fn push_word(&mut self, word: &'static str);
+    /// Receives a hyphenated compound (***twenty-one***).
+    fn push_compound(&mut self, tens: &'static str, ones: &'static str);
+}
+
+impl WordSink for Vec<String> {
+    fn push_word(&mut self, word: &'static str) {
+        self.push(word.to_string());
     }
-    let ones_and_tens = n % 100;
-    if ones_and_tens != 0 {
-        lt100(ones_and_tens as u8, words);
+
+    fn push_compound(&mut self, tens: &'static str, ones: &'static str) {
+        let mut word = String::with_capacity(tens.len() + 1 + ones.len());
+        word.push_str(tens);
+        word.push('-');
+        word.push_str(ones);
+        self.push(word);
     }
 }
 
-fn lt100(n: u8, words: &mut Vec<String>) {
-    const NUMS_SMALLER_THAN_20: [&str; 19] = [
-        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven",
-        "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
-    ];
-    const NUMS_SMALLER_THAN_20_OFFSET: usize = 1;
-    const MULTIPLES_OF_10: [&str; 8] = [
-        "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
-    ];
-    const MULTIPLES_OF_10_OFFSET: usize = 2;
+/// Appends the words to `out`, separated by spaces from each other and from anything written since
+/// `start`.
+struct StringSink<'a> {
+    out: &'a mut String,
+    start: usize,
+}
+
+impl WordSink for StringSink<'_> {
+    fn push_word(&mut self, word: &'static str) {
+        write_word_separator(self.out, self.start);
+        self.out.push_str(word);
+    }
+
+    fn push_compound(&mut self, tens: &'static str, ones: &'static str) {
+        write_word_separator(self.out, self.start);
+        self.out.push_str(tens);
+        self.out.push('-');
+        self.out.push_str(ones);
+    }
+}
+
+/// Pushes the words of `n` (below 1000) to `words`. With [WordsOptions::british], ***and*** is put
+/// between the hundreds and the tens/ones.
+fn lt1000(n: u16, options: &WordsOptions, words: &mut Vec<String>) {
+    sink_lt1000(n, options, words);
+}
+
+const NUMS_SMALLER_THAN_20: [&str; 19] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven",
+    "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+const NUMS_SMALLER_THAN_20_OFFSET: usize = 1;
+const MULTIPLES_OF_10: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+const MULTIPLES_OF_10_OFFSET: usize = 2;
+
+/// Returns the word of any number below 20 (***zero*** to ***nineteen***). Usable in `const` contexts.
+///
+/// # Panics
+/// Panics if `n` is 20 or larger (at compile time, when used in a `const` context).
+///
+/// # Example
+/// ```
+/// use num2en::word_below_20;
+///
+/// const WORDS: [&str; 3] = [word_below_20(0), word_below_20(7), word_below_20(19)];
+/// assert_eq!(WORDS, ["zero", "seven", "nineteen"]);
+/// ```
+pub const fn word_below_20(n: u8) -> &'static str {
+    match n {
+        0 => "zero",
+        _ => NUMS_SMALLER_THAN_20[n as usize - NUMS_SMALLER_THAN_20_OFFSET],
+    }
+}
+
+/// Returns the word of a multiple of ten, given its tens digit (***zero***, ***ten***, ***twenty*** to
+/// ***ninety***). Usable in `const` contexts.
+///
+/// # Panics
+/// Panics if `tens` is 10 or larger (at compile time, when used in a `const` context).
+///
+/// # Example
+/// ```
+/// use num2en::multiple_of_ten;
+///
+/// const FORTY: &str = multiple_of_ten(4);
+/// assert_eq!(FORTY, "forty");
+/// assert_eq!(multiple_of_ten(1), "ten");
+/// ```
+pub const fn multiple_of_ten(tens: u8) -> &'static str {
+    match tens {
+        0 | 1 => word_below_20(tens * 10),
+        _ => MULTIPLES_OF_10[tens as usize - MULTIPLES_OF_10_OFFSET],
+    }
+}
+
+/// Pushes the word of `n` (between 1 and 99) to `words`. Without [WordsOptions::hyphenate], the
+/// tens and the ones of compounds are pushed as separate words (***twenty one***).
+fn lt100(n: u8, options: &WordsOptions, words: &mut Vec<String>) {
+    sink_lt100(n, options, words);
+}
+
+/// Appends the word of `n` (between 1 and 99) to `out`, preceded by a space if `out` is not empty.
+fn write_lt100(n: u8, options: &WordsOptions, out: &mut String) {
+    sink_lt100(n, options, &mut StringSink { out, start: 0 });
+}
+
+/// Appends the words of `n` (below 1000) to `out`, preceded by a space if anything has been written
+/// since `start`.
+fn write_lt1000(n: u16, options: &WordsOptions, out: &mut String, start: usize) {
+    sink_lt1000(n, options, &mut StringSink { out, start });
+}
 
+/// Passes the words of `n` (between 1 and 99) to `sink`.
+fn sink_lt100<S: WordSink>(n: u8, options: &WordsOptions, sink: &mut S) {
     if n < 20 {
-        words.push(NUMS_SMALLER_THAN_20[n as usize - NUMS_SMALLER_THAN_20_OFFSET].to_string());
+        sink.push_word(word_below_20(n));
     }
     else {
         let tens = n / 10;  // guaranteed to be at least 2 (because of previous check)
         let ones = n % 10;
-        let mut word = MULTIPLES_OF_10[tens as usize - MULTIPLES_OF_10_OFFSET].to_string();
-        if ones != 0 {
-            word += "-";
-            word += NUMS_SMALLER_THAN_20[ones as usize - NUMS_SMALLER_THAN_20_OFFSET];
+        if ones == 0 {
+            sink.push_word(multiple_of_ten(tens));
         }
-        words.push(word);
+        else if options.hyphenate {
+            sink.push_compound(multiple_of_ten(tens), word_below_20(ones));
+        }
+        else {
+            sink.push_word(multiple_of_ten(tens));
+            sink.push_word(word_below_20(ones));
+        }
+    }
+}
+
+/// Passes the words of `n` (below 1000) to `sink`.
+fn sink_lt1000<S: WordSink>(n: u16, options: &WordsOptions, sink: &mut S) {
+    let hundreds = n / 100;
+    if hundreds != 0 {
+        sink_lt100(hundreds as u8, options, sink);
+        sink.push_word("hundred");
+    }
+    let ones_and_tens = n % 100;
+    if ones_and_tens != 0 {
+        if options.british && hundreds != 0 {
+            sink.push_word("and");
+        }
+        sink_lt100(ones_and_tens as u8, options, sink);
     }
 }
 
+fn write_word_separator(out: &mut String, start: usize) {
+    if out.len() > start {
+        out.push(' ');
+    }
+}
 
 /// names of periods (10 ** 3k)
-const PERIODS: [&str; 12] = [
+const PERIODS: [&str; 21] = [
     "thousand", "million", "billion", "trillion", "quadrillion", "quintillion",
     "sextillion", "septillion", "octillion", "nonillion", "decillion", "undecillion",
+    "duodecillion", "tredecillion", "quattuordecillion", "quindecillion", "sexdecillion",
+    "septendecillion", "octodecillion", "novemdecillion", "vigintillion",
 ];
 
+/// Number of named periods, from ***thousand*** (10<sup>3</sup>) to ***vigintillion*** (10<sup>63</sup>).
+/// <br> Numbers with up to `3 * (MAX_SUPPORTED_PERIODS + 1)` (66) integer digits can be spelled by [str_to_words_large].
+pub const MAX_SUPPORTED_PERIODS: usize = PERIODS.len();
+
+/// number of periods below `u128::MAX` (up to undecillion)
+const U128_PERIODS: usize = 12;
+
+/// Pushes the name of the period `idx` to `words`, which must end with the (non-zero) group it names.
+fn push_period(idx: usize, words: &mut Vec<String>) {
+    debug_assert!(
+        matches!(words.last(), Some(word) if word != "zero" && !PERIODS.contains(&word.as_str())),
+        "period name \"{}\" without a preceding group in {:?}", PERIODS[idx], words
+    );
+    words.push(PERIODS[idx].to_string());
+}
+
+/// Calls `f` with each non-zero group of digits of `n` and the group's index (0 for the least
+/// significant group), from the most significant group.
+/// <br> `group_sizes` are the numbers of digits in each group, starting with the least significant
+/// one, and the last size is repeated. After `named_groups` named groups, all the remaining digits
+/// form a single group, which can be 1000 or more.
+fn for_each_digit_group<F: FnMut(u128, usize)>(n: u128, group_sizes: &[usize], named_groups: usize, mut f: F) {
+    // u128::MAX has 39 digits, so at most 39 groups of one digit plus an empty rest
+    let mut groups = [0u128; 40];
+    let mut len = 0;
+    let mut rest = n;
+    while rest != 0 {
+        if len == named_groups {
+            groups[len] = rest;
+            len += 1;
+            break;
+        }
+        let size = group_sizes[len.min(group_sizes.len() - 1)];
+        match u32::try_from(size).ok().and_then(|size| 10u128.checked_pow(size)) {
+            Some(divisor) => {
+                groups[len] = rest % divisor;
+                rest /= divisor;
+            },
+            None => {
+                groups[len] = rest;
+                rest = 0;
+            },
+        }
+        len += 1;
+    }
+
+    for (i, &group) in groups[..len].iter().enumerate().rev() {
+        if group != 0 {
+            f(group, i);
+        }
+    }
+}
+
+/// Pushes the words of the non-zero `n` to `words`, with the periods named by [PERIODS].
+fn push_period_words(n: u128, words: &mut Vec<String>) {
+    for_each_digit_group(n, &[3], PERIODS.len(), |group, idx| {
+        lt1000(group as u16, &DEFAULT_WORDS_OPTIONS, words);
+        if idx > 0 {
+            push_period(idx - 1, words);
+        }
+    });
+}
+
 macro_rules! create_public_conversion_func_of_unsigned_int {
-    ( $t:ty, $name:ident, $num_of_periods:literal ) => {
+    ( $t:ty, $name:ident, $write_name:ident ) => {
         /// Converts any
         #[doc = concat!("`", stringify!($t), "`")]
         /// value to its **cardinal** number representation in words (***one, two, three*** etc.).
@@ -139,40 +341,57 @@ macro_rules! create_public_conversion_func_of_unsigned_int {
         /// # Notes
         ///
         /// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-one").
+        #[doc = concat!("- This function uses [", stringify!($write_name), "] behind the curtains.")]
         pub fn $name(n: $t) -> String {
+            let mut words = String::new();
+            $write_name(n, &mut words);
+            return words;
+        }
+
+        /// Appends the **cardinal** number representation in words of any
+        #[doc = concat!("`", stringify!($t), "`")]
+        /// value to `out`, without allocating any intermediate strings.
+        ///
+        /// # Arguments
+        ///
+        /// - `n`: An unsigned integer
+        #[doc = concat!("(`", stringify!($t), "`)")]
+        /// that represents the number to be converted.
+        /// - `out`: The [`String`] the words are appended to. It is not cleared first, and no space
+        ///   is put between its previous contents and the words.
+        ///
+        #[doc = concat!(
+            "# Example\n\
+            ```\n\
+            use num2en::", stringify!($write_name), ";\n\n\
+            let mut label = \"Item \".to_string();\n\
+            ", stringify!($write_name), "(12_142, &mut label);\n\
+            assert_eq!(label, \"Item twelve thousand one hundred forty-two\");\n\
+            ```"
+        )]
+        pub fn $write_name(n: $t, out: &mut String) {
             if n == 0 {
-                return "zero".to_string();
+                out.push_str("zero");
+                return;
             }
 
-            let mut words = Vec::<String>::new();
-
-            let mut divisor = (1000 as $t).pow($num_of_periods);
-            let mut idx = $num_of_periods;
-            while divisor >= 1000 {
-                idx -= 1;
-                let current_period = (n / divisor) % 1000;
-                if current_period != 0 {
-                    lt1000(current_period as u16, &mut words);
-                    words.push(PERIODS[idx].to_string());
+            let start = out.len();
+            for_each_digit_group(n as u128, &[3], PERIODS.len(), |group, idx| {
+                write_lt1000(group as u16, &DEFAULT_WORDS_OPTIONS, out, start);
+                if idx > 0 {
+                    out.push(' ');
+                    out.push_str(PERIODS[idx - 1]);
                 }
-                divisor /= 1000;
-            }
-
-            lt1000((n % 1000) as u16, &mut words);
-
-            return words.join(" ");
+            });
         }
     };
 }
 
-#[cfg(target_pointer_width = "64")]
-create_public_conversion_func_of_unsigned_int!(usize, usize_to_words, 6);
-#[cfg(target_pointer_width = "32")]
-create_public_conversion_func_of_unsigned_int!(usize, usize_to_words, 3);
-create_public_conversion_func_of_unsigned_int!(u128, u128_to_words, 12);
-create_public_conversion_func_of_unsigned_int!(u64, u64_to_words, 6);
-create_public_conversion_func_of_unsigned_int!(u32, u32_to_words, 3);
-create_public_conversion_func_of_unsigned_int!(u16, u16_to_words, 1);
+create_public_conversion_func_of_unsigned_int!(usize, usize_to_words, write_usize_words);
+create_public_conversion_func_of_unsigned_int!(u128, u128_to_words, write_u128_words);
+create_public_conversion_func_of_unsigned_int!(u64, u64_to_words, write_u64_words);
+create_public_conversion_func_of_unsigned_int!(u32, u32_to_words, write_u32_words);
+create_public_conversion_func_of_unsigned_int!(u16, u16_to_words, write_u16_words);
 /// Converts any `u8` value to its **cardinal** number representation in words (***one, two, three*** etc.).
 ///
 /// # Arguments
@@ -192,544 +411,5612 @@ create_public_conversion_func_of_unsigned_int!(u16, u16_to_words, 1);
 ///
 /// # Notes
 /// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-one").
+/// - This function uses [write_u8_words] behind the curtains.
+#[allow(clippy::needless_return)]
 pub fn u8_to_words(n: u8) -> String {
+    let mut words = String::new();
+    write_u8_words(n, &mut words);
+    return words;
+}
+
+/// Appends the **cardinal** number representation in words of any `u8` value to `out`, without
+/// allocating any intermediate strings.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u8`) that represents the number to be converted.
+/// - `out`: The [`String`] the words are appended to. It is not cleared first, and no space
+///   is put between its previous contents and the words.
+///
+/// # Example
+/// ```
+/// use num2en::write_u8_words;
+///
+/// let mut label = "Item ".to_string();
+/// write_u8_words(142, &mut label);
+/// assert_eq!(label, "Item one hundred forty-two");
+/// ```
+pub fn write_u8_words(n: u8, out: &mut String) {
     if n == 0 {
-        return "zero".to_string();
+        out.push_str("zero");
+        return;
     }
-    let mut words = Vec::<String>::new();
-    lt1000(n as u16, &mut words);
-    return words.join(" ");
+    write_lt1000(n as u16, &DEFAULT_WORDS_OPTIONS, out, out.len());
 }
 
 
-const ORD_NUMS_EXCEPTIONS: [(&str, &str); 7] = [
-    ("one", "first"), ("two", "second"), ("three", "third"), ("five", "fifth"),
-    ("eight", "eighth"), ("nine", "ninth"), ("twelve", "twelfth"),
-];
-
-macro_rules! create_public_conversion_func_of_unsigned_int_ord {
-    ( $t:ty, $name:ident, $num_of_periods:literal ) => {
-        /// Converts any
-        #[doc = concat!("`", stringify!($t), "`")]
-        /// value to its **ordinal** number representation in words (***first, second, third*** etc.).
-        ///
-        /// # Arguments
-        ///
-        /// - `n`: An unsigned integer
-        #[doc = concat!("(`", stringify!($t), "`)")]
-        /// that represents the number to be converted.
-        ///
-        /// # Returns
-        ///
-        /// A [`String`] containing the English words that represent the input ordinal number.
-        ///
-        #[doc = concat!(
-            "# Example\n\
-            ```\n\
-            use num2en::", stringify!($name), ";\n\n\
-            let number = 12;\n\
-            let words = ", stringify!($name), "(number);\n\
-            assert_eq!(words, \"twelfth\");\n\n\
-            let number = 12_142;\n\
-            let words = ", stringify!($name), "(number);\n\
-            assert_eq!(words, \"twelve thousand one hundred forty-second\");\n\
-            ```"
-        )]
-        ///
-        /// # Notes
-        ///
-        /// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-first").
-        pub fn $name(n: $t) -> String {
-            if n == 0 {
-                return "zeroth".to_string();
-            }
-
-            let mut words = Vec::<String>::new();
+/// Converts every `u128` value in a slice to its **cardinal** number representation in words.
+///
+/// # Arguments
+/// - `nums`: A slice of unsigned integers (`u128`) to be converted.
+///
+/// # Returns
+/// A [`Vec`]`<`[`String`]`>` with the words of each number, in the same order.
+///
+/// # Example
+/// ```
+/// use num2en::u128_slice_to_words;
+///
+/// assert_eq!(u128_slice_to_words(&[1, 20, 300]), vec!["one", "twenty", "three hundred"]);
+/// ```
+///
+/// # Notes
+/// - This function uses [u128_slice_write_words] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn u128_slice_to_words(nums: &[u128]) -> Vec<String> {
+    let mut words = Vec::with_capacity(nums.len());
+    u128_slice_write_words(nums, &mut words);
+    return words;
+}
 
-            let mut divisor = (1000 as $t).pow($num_of_periods);
-            let mut idx = $num_of_periods;
-            while divisor >= 1000 {
-                idx -= 1;
-                let current_period = (n / divisor) % 1000;
-                if current_period != 0 {
-                    lt1000(current_period as u16, &mut words);
-                    words.push(PERIODS[idx].to_string());
-                }
-                divisor /= 1000;
-            }
+/// Converts every `u128` value in a slice to words, reusing the strings already in `out`.
+///
+/// # Arguments
+/// - `nums`: A slice of unsigned integers (`u128`) to be converted.
+/// - `out`: The [`Vec`]`<`[`String`]`>` that receives the words of each number, in the same order.
+///   Its previous contents are overwritten, and it is resized to the length of `nums`.
+///
+/// # Example
+/// ```
+/// use num2en::u128_slice_write_words;
+///
+/// let mut words = Vec::new();
+/// for batch in [[1, 2], [30, 400]] {
+///     u128_slice_write_words(&batch, &mut words);
+/// }
+/// assert_eq!(words, vec!["thirty", "four hundred"]);
+/// ```
+///
+/// # Notes
+/// - The strings from a previous call are cleared and written again, so converting batches of
+///   similar numbers into the same vector allocates (almost) nothing after the first batch.
+/// - This function uses [write_u128_words] behind the curtains.
+pub fn u128_slice_write_words(nums: &[u128], out: &mut Vec<String>) {
+    out.truncate(nums.len());
+    for (i, &n) in nums.iter().enumerate() {
+        match out.get_mut(i) {
+            Some(words) => {
+                words.clear();
+                write_u128_words(n, words);
+            },
+            None => {
+                let mut words = String::new();
+                write_u128_words(n, &mut words);
+                out.push(words);
+            },
+        }
+    }
+}
 
-            lt1000((n % 1000) as u16, &mut words);
 
-            // Modify the last word to an ordinal word
-            let mut last_word = &words.pop().unwrap()[..];
-            let mut penultimate_word = "";
-            if let Some(hyphen_index) = last_word.find('-') {
-                penultimate_word = &last_word[.. hyphen_index + 1];
-                last_word = &last_word[hyphen_index + 1 ..];
-            }
-            if let Some(index) = ORD_NUMS_EXCEPTIONS.iter().position(|x| x.0 == last_word) {
-                words.push(penultimate_word.to_string() + ORD_NUMS_EXCEPTIONS[index].1);
-            }
-            else if last_word.ends_with("y") {
-                words.push(penultimate_word.to_string() + &last_word[.. last_word.len() - 1] + "ieth");
-            }
-            else {
-                words.push(penultimate_word.to_string() + last_word + "th");
-            }
+/// Splits the words of `n` into one phrase per non-zero period, from the largest
+/// (***one million***, ***two hundred thousand***, ***five***).
+#[allow(clippy::needless_return)]
+fn period_groups(n: u128, options: &WordsOptions) -> Vec<String> {
+    if n == 0 {
+        return vec!["zero".to_string()];
+    }
 
-            return words.join(" ");
+    let mut groups = Vec::<String>::new();
+    for_each_digit_group(n, &[3], PERIODS.len(), |group, idx| {
+        let mut words = Vec::<String>::new();
+        lt1000(group as u16, options, &mut words);
+        if idx > 0 {
+            push_period(idx - 1, &mut words);
         }
-    };
+        groups.push(words.join(" "));
+    });
+    return groups;
 }
 
-#[cfg(target_pointer_width = "64")]
-create_public_conversion_func_of_unsigned_int_ord!(usize, usize_to_ord_words, 6);
-#[cfg(target_pointer_width = "32")]
-create_public_conversion_func_of_unsigned_int_ord!(usize, usize_to_ord_words, 3);
-create_public_conversion_func_of_unsigned_int_ord!(u128, u128_to_ord_words, 12);
-create_public_conversion_func_of_unsigned_int_ord!(u64, u64_to_ord_words, 6);
-create_public_conversion_func_of_unsigned_int_ord!(u32, u32_to_ord_words, 3);
-create_public_conversion_func_of_unsigned_int_ord!(u16, u16_to_ord_words, 1);
-/// Converts any `u8` value to its **ordinal** number representation in words (***first, second, third*** etc.).
+/// Converts any `u128` value to its **cardinal** number representation in words, with custom
+/// text between the period groups (***one million, two hundred thousand, and five***).
 ///
 /// # Arguments
-/// - `n`: An unsigned integer (`u8`) that represents the number to be converted.
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+/// - `separator`: The text placed between consecutive period groups (e.g. `", "` or `" "`).
+/// - `final_connector`: A word placed before the last group when that group is below one thousand
+///   (e.g. `"and"`), or `""` for none.
+/// - `oxford_comma`: Whether `separator` is also placed before the final connector
+///   (***..., and five***, as in US usage) or just a space (***... and five***, as in UK usage).
 ///
 /// # Returns
-/// A [`String`] containing the English words that represent the input ordinal number.
+/// A [`String`] containing the period groups joined by `separator`.
+/// <br> The final connector is followed by a space and only appears when the number has a non-zero
+/// part below one thousand and at least one larger period.
 ///
 /// # Examples
 /// ```
-/// use num2en::u8_to_ord_words;
-/// 
-/// let number = 13;
-/// let words = u8_to_ord_words(number);
-/// assert_eq!(words, "thirteenth");
-/// 
-/// let number = 142;
-/// let words = u8_to_ord_words(number);
-/// assert_eq!(words, "one hundred forty-second");
+/// use num2en::u128_to_words_with_connectors;
+///
+/// let words = u128_to_words_with_connectors(1_200_005, ", ", "and", true);
+/// assert_eq!(words, "one million, two hundred thousand, and five");
+///
+/// let words = u128_to_words_with_connectors(1_200_005, ", ", "and", false);
+/// assert_eq!(words, "one million, two hundred thousand and five");
+///
+/// let words = u128_to_words_with_connectors(1_005, " ", "and", true);
+/// assert_eq!(words, "one thousand and five");
+///
+/// let words = u128_to_words_with_connectors(3_000_000, ", ", "and", true);
+/// assert_eq!(words, "three million");
 /// ```
 ///
 /// # Notes
-/// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-first").
-pub fn u8_to_ord_words(n: u8) -> String { u16_to_ord_words(n as u16) }
-
+/// - With `" "` and `""` the result is the same as [u128_to_words].
+/// - The connector is not added inside a group, so ***one hundred five*** stays as it is.
+/// - [WordsOptions::oxford_comma] controls the same choice for [IntegerStyle::Commas].
+#[allow(clippy::needless_return)]
+pub fn u128_to_words_with_connectors(n: u128, separator: &str, final_connector: &str, oxford_comma: bool) -> String {
+    let mut groups = period_groups(n, &DEFAULT_WORDS_OPTIONS);
+    if !final_connector.is_empty() && groups.len() > 1 && n % 1000 != 0 {
+        let last = groups.pop().unwrap();
+        let final_separator = if oxford_comma { separator } else { " " };
+        return groups.join(separator) + final_separator + final_connector + " " + &last;
+    }
+    return groups.join(separator);
+}
 
-macro_rules! create_public_conversion_func_of_signed_int {
-    ( $t:tt, $name:ident, $num_of_periods:literal ) => {
-        /// Converts any
-        #[doc = concat!("`", stringify!($t), "`")]
-        /// value to its **cardinal** number representation in words (***one, two, three*** etc.).
-        ///
-        /// # Arguments
-        ///
-        /// - `n`: A signed integer
-        #[doc = concat!("(`", stringify!($t), "`)")]
-        /// that represents the number to be converted.
-        ///
-        /// # Returns
-        ///
-        /// A [`String`] containing the English words that represent the input cardinal number.
-        ///
-        #[doc = concat!(
-            "# Example\n\
-            ```\n\
-            use num2en::", stringify!($name), ";\n\n\
-            let number = 1969;\n\
-            let words = ", stringify!($name), "(number);\n\
-            assert_eq!(words, \"one thousand nine hundred sixty-nine\");\n\n\
-            let number = -2918;\n\
-            let words = ", stringify!($name), "(number);\n\
-            assert_eq!(words, \"negative two thousand nine hundred eighteen\");\n\
-            ```"
-        )]
-        ///
-        /// # Notes
-        ///
-        /// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-one").
-        pub fn $name(n: $t) -> String {
-            if n == 0 {
-                return "zero".to_string();
-            }
+/// Converts any `u128` value to its **cardinal** number representation in words, split into one
+/// string per period.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`Vec`]`<`[`String`]`>` with one entry per non-zero period, from the largest, each made of the
+/// spelled group and its period name. Joined with spaces, the entries give the same words as [u128_to_words].
+/// <br> Zero gives a single ***zero*** entry.
+///
+/// # Example
+/// ```
+/// use num2en::u128_to_word_groups;
+///
+/// assert_eq!(
+///     u128_to_word_groups(1_234_567),
+///     vec!["one million", "two hundred thirty-four thousand", "five hundred sixty-seven"]
+/// );
+/// assert_eq!(u128_to_word_groups(5_000_001), vec!["five million", "one"]);
+/// ```
+#[allow(clippy::needless_return)]
+pub fn u128_to_word_groups(n: u128) -> Vec<String> {
+    return period_groups(n, &DEFAULT_WORDS_OPTIONS);
+}
 
-            let mut words = Vec::<String>::new();
 
-            type UnsignedType = signed_to_unsigned!($t);
-            let mut nonnegative_n = n as UnsignedType;
-            if n < 0 {
-                words.push("negative".to_string());
-                if n > <$t>::MIN {
-                    // values in range (iX::MIN, 0) don't map correctly to uX without negating first
-                    nonnegative_n = -n as UnsignedType;
-                }
-            }
-
-            let mut divisor = (1000 as UnsignedType).pow($num_of_periods);
-            let mut idx = $num_of_periods;
-            while divisor >= 1000 {
-                idx -= 1;
-                let current_period = (nonnegative_n / divisor) % 1000;
-                if current_period != 0 {
-                    lt1000(current_period as u16, &mut words);
-                    words.push(PERIODS[idx].to_string());
-                }
-                divisor /= 1000;
-            }
-
-            lt1000((nonnegative_n % 1000) as u16, &mut words);
-
-            return words.join(" ");
-        }
-    };
-}
-
-macro_rules! signed_to_unsigned {
-    (i16) => { u16 };
-    (i32) => { u32 };
-    (i64) => { u64 };
-    (i128) => { u128 };
-    (isize) => { usize };
-}
-
-#[cfg(target_pointer_width = "64")]
-create_public_conversion_func_of_signed_int!(isize, isize_to_words, 6);
-#[cfg(target_pointer_width = "32")]
-create_public_conversion_func_of_signed_int!(isize, isize_to_words, 3);
-create_public_conversion_func_of_signed_int!(i128, i128_to_words, 12);
-create_public_conversion_func_of_signed_int!(i64, i64_to_words, 6);
-create_public_conversion_func_of_signed_int!(i32, i32_to_words, 3);
-create_public_conversion_func_of_signed_int!(i16, i16_to_words, 1);
-/// Converts any `u8` value to its **cardinal** number representation in words (***one, two, three*** etc.).
+/// Converts any `u128` value to its **cardinal** number representation in words, using British
+/// English (***one hundred and twenty-three***).
 ///
 /// # Arguments
-/// - `n`: A signed integer (`u8`) that represents the number to be converted.
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
 ///
 /// # Returns
-/// A [`String`] containing the English words that represent the input cardinal number.
+/// A [`String`] containing the English words that represent the input cardinal number, with
+/// ***and*** between the hundreds and the tens/ones of every period.
+/// <br> When the last period is below one hundred and follows a larger period, ***and*** is put
+/// before it as well (***two thousand and five***).
 ///
 /// # Examples
 /// ```
-/// use num2en::i8_to_words;
+/// use num2en::u128_to_words_british;
 ///
-/// let number = 120;
-/// let words = i8_to_words(number);
-/// assert_eq!(words, "one hundred twenty");
-///
-/// let number = -111;
-/// let words = i8_to_words(number);
-/// assert_eq!(words, "negative one hundred eleven");
+/// assert_eq!(u128_to_words_british(123), "one hundred and twenty-three");
+/// assert_eq!(u128_to_words_british(2_305), "two thousand three hundred and five");
+/// assert_eq!(u128_to_words_british(2_005), "two thousand and five");
+/// assert_eq!(
+///     u128_to_words_british(101_101),
+///     "one hundred and one thousand one hundred and one"
+/// );
 /// ```
 ///
 /// # Notes
-/// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-one").
-pub fn i8_to_words(n: i8) -> String {
-    if n == 0 {
-        return "zero".to_string();
-    }
-    let mut words = Vec::<String>::new();
-    let mut nonnegative_n = n as u8;
-    if n < 0 {
-        words.push("negative".to_string());
-        if n > i8::MIN {
-            nonnegative_n = -n as u8;
-        }
-    }
-    lt1000(nonnegative_n as u16, &mut words);
-    return words.join(" ");
+/// - This function uses [WordsOptions::british] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn u128_to_words_british(n: u128) -> String {
+    return WordsOptions::new().british(true).u128_to_words(n);
 }
 
-
-#[derive(Debug, PartialEq)]
-/// Represents the possible error that can occur when calling [str_digits_to_words].
-pub enum DigitConversionError {
-    /// Indicates that the string contains a character other than `0`, `1`, `2`, `3`, `4`, `5`, `6`, `7`, `8`, or `9`.
-    InvalidCharacter,
+/// Same as [str_to_words], except that the integer part is spelled using British English
+/// (see [u128_to_words_british]).
+///
+/// # Example
+/// ```
+/// use num2en::str_to_words_british;
+///
+/// let words = str_to_words_british("-2305.05");
+/// assert_eq!(words, Ok("negative two thousand three hundred and five point zero five".to_string()));
+/// ```
+///
+/// # Notes
+/// - This function uses [WordsOptions::british] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn str_to_words_british(string: &str) -> Result<String, StrConversionError> {
+    return WordsOptions::new().british(true).str_to_words(string);
 }
 
-/// Converts any string of digits (`0`-`9`) to a string of all the digits spelled out individually.
+/// Converts any finite `f64` value to words, rounded to exactly `decimals` fractional digits.
 ///
 /// # Arguments
-/// - `digits`: `&str` of digits to be converted.
+/// - `f`: A floating-point number (`f64`) that represents the number to be converted.
+/// - `decimals`: The number of fractional digits to spell.
 ///
 /// # Returns
-/// [`Result`]`<`[`String`]`, `[`DigitConversionError`]`>`
-/// 
-/// The string contains all the digits spelled out individually.
-/// 
-/// For example, `"123"` becomes `"one two three"`.
+/// A `Result` containing:
+/// - `Ok(String)`: The words of `f` formatted with `decimals` fractional digits, including trailing zeros.
+/// - `Err(FloatConversionError)`: [FloatConversionError::NotFinite] if `f` is NaN or infinite,
+///   or [FloatConversionError::TooLarge] if its integer part exceeds 2^128 - 1.
 ///
-/// # Examples
+/// # Example
 /// ```
-/// use num2en::str_digits_to_words;
-/// # use num2en::DigitConversionError;
-/// 
-/// let digits = "12408842";
-/// let result = str_digits_to_words(digits);
-/// assert_eq!(result, Ok("one two four zero eight eight four two".to_string()));
-/// 
-/// let digits = "00015000";
-/// let result = str_digits_to_words(digits);
-/// assert_eq!(result, Ok("zero zero zero one five zero zero zero".to_string()));
-/// 
-/// // A string with non-digit characters results in an error.
-/// let invalid_string = "124brb";
-/// let result = str_digits_to_words(invalid_string);
-/// assert_eq!(result, Err(DigitConversionError::InvalidCharacter));
-/// 
-/// // An empty string doesn't do anything.
-/// let empty_string = "";
-/// let result = str_digits_to_words(empty_string);
-/// assert_eq!(result, Ok("".to_string()));
+/// use num2en::f64_to_words_prec;
+/// # use num2en::FloatConversionError;
+///
+/// assert_eq!(f64_to_words_prec(0.1 + 0.2, 2), Ok("zero point three zero".to_string()));
+/// assert_eq!(f64_to_words_prec(-2.5, 0), Ok("negative two".to_string()));
+/// assert_eq!(f64_to_words_prec(7.0, 1), Ok("seven point zero".to_string()));
+/// assert_eq!(f64_to_words_prec(-0.001, 2), Ok("zero point zero zero".to_string()));
+/// assert_eq!(f64_to_words_prec(f64::NAN, 1), Err(FloatConversionError::NotFinite));
 /// ```
-pub fn str_digits_to_words(digits: &str) -> Result<String, DigitConversionError> {
-    let mut words = Vec::with_capacity(digits.len());
-    for digit in digits.chars() {
-        words.push(match digit {
-            '0' => "zero",
-            '1' => "one",
-            '2' => "two",
-            '3' => "three",
-            '4' => "four",
-            '5' => "five",
-            '6' => "six",
-            '7' => "seven",
-            '8' => "eight",
-            '9' => "nine",
-            _ => return Err(DigitConversionError::InvalidCharacter)
-        });
-    }
-    Ok(words.join(" "))
-}
-
-
-#[derive(Debug, PartialEq)]
-/// Represents the possible errors that can occur when calling [str_to_words].
-pub enum StrConversionError {
-    /// This could mean the string contains invalid characters or is in an incorrect format.
-    InvalidString,
-    /// Indicates that the value is too large to be converted.
-    TooLarge,
+///
+/// # Notes
+/// - The value is formatted like `format!("{:.*}", decimals, f)`, so it is rounded the same way.
+/// - `decimals` greater than 1074 are treated as 1074; no `f64` has a non-zero digit past that.
+/// - A negative value that is or rounds to zero is read without its sign ([SignedZero::Suppress]).
+/// - To leave out the trailing zeros, use [WordsOptions::f64_to_words_prec] with
+///   [WordsOptions::trim_fractional_zeros].
+#[allow(clippy::needless_return)]
+pub fn f64_to_words_prec(f: f64, decimals: usize) -> Result<String, FloatConversionError> {
+    return DEFAULT_WORDS_OPTIONS.f64_to_words_prec(f, decimals);
 }
 
-/// Converts any* string of a (decimal) number to a number representation in words.
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Options for spelling numbers, combined with builder methods and used through the conversion
+/// methods ([WordsOptions::u128_to_words], [WordsOptions::i128_to_words], [WordsOptions::str_to_words]).
 ///
-/// # Arguments
-/// - `string`: `&str` representing a number in the `... xxxxxx.xxxxxx ...` format, where `x` is any digit.
-/// <br> * The integer part must be 2<sup>128</sup> - 1 (~ 340 undecillion) or smaller, while
-/// the decimal part is unrestricted.
+/// # Defaults
+/// - `british`: `false`
+/// - `capitalize`: `false`
+/// - `negative_word`: `"negative"`
+/// - `point_word`: `"point"`
+/// - `trim_fractional_zeros`: `false`
+/// - `hyphenate`: `true`
+/// - `trailing_point`: `true`
+/// - `integer_style`: [IntegerStyle::Plain]
+/// - `fraction_style`: [FractionStyle::Digits]
+/// - `oxford_comma`: `false`
+/// - `sign_position`: [SignPosition::Prefix]
 ///
-/// # Returns
-/// [`Result`]`<`[`String`]`, `[`StrConversionError`]`>`
-/// 
-/// The string contains the English words that represent the input number.
-/// 
-/// For example, `"123.456"` becomes `"one hundred twenty-three point four five six"`.
+/// With the default options, the conversion methods give the same results as [u128_to_words],
+/// [i128_to_words] and [str_to_words].
 ///
-/// # Examples
+/// # Example
 /// ```
-/// use num2en::str_to_words;
-/// # use num2en::StrConversionError;
-/// 
-/// let number = "123.123";
-/// let result = str_to_words(number);
-/// assert_eq!(result, Ok("one hundred twenty-three point one two three".to_string()));
-/// 
-/// let number = "1095";
-/// let result = str_to_words(number);
-/// assert_eq!(result, Ok("one thousand ninety-five".to_string()));
-/// 
-/// let number = "0.0042";
-/// let result = str_to_words(number);
-/// assert_eq!(result, Ok("zero point zero zero four two".to_string()));
+/// use num2en::WordsOptions;
 ///
-/// let number = ".0042";
-/// let result = str_to_words(number);
-/// assert_eq!(result, Ok("point zero zero four two".to_string()));
-/// 
-/// let number = "1095.";
-/// let result = str_to_words(number);
-/// assert_eq!(result, Ok("one thousand ninety-five point".to_string()));
-/// 
-/// // Leading zeros are ignored.
-/// let number = "0003000";
-/// let result = str_to_words(number);
-/// assert_eq!(result, Ok("three thousand".to_string()));
-/// 
-/// // This is (almost) the largest allowed number (it could have any number of nines):
-/// let number = "340282366920938463463374607431768211455.99999999";
-/// let result = str_to_words(number);
-/// assert_eq!(result, Ok("three hundred forty undecillion two hundred eighty-two \
-/// decillion three hundred sixty-six nonillion nine hundred twenty octillion nine \
-/// hundred thirty-eight septillion four hundred sixty-three sextillion four hundred \
-/// sixty-three quintillion three hundred seventy-four quadrillion six hundred seven \
-/// trillion four hundred thirty-one billion seven hundred sixty-eight million two \
-/// hundred eleven thousand four hundred fifty-five point nine nine nine nine nine \
-/// nine nine nine".to_string()));
-/// 
-/// // A string with invalid characters results in an error.
-/// let invalid_string = "235:53";
-/// let result = str_to_words(invalid_string);
-/// assert_eq!(result, Err(StrConversionError::InvalidString));
-/// 
-/// // An empty string doesn't do anything.
-/// let empty_string = "";
-/// let result = str_to_words(empty_string);
-/// assert_eq!(result, Ok("".to_string()));
+/// let options = WordsOptions::new().british(true).capitalize(true).negative_word("minus");
+/// assert_eq!(options.i128_to_words(-123), "Minus one hundred and twenty-three");
+/// assert_eq!(options.str_to_words("2005.5"), Ok("Two thousand and five point five".to_string()));
 /// ```
-/// 
-/// # Notes
-/// - Scientific notation (e.g. `"4.2e1"`) is not supported.
-/// - This function supports only numbers between `-u128::MAX-1` (exclusive) and `u128::MAX+1` (exclusive).
-/// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-one").
-/// - This function uses [u128_to_words] and [str_digits_to_words] behind the curtains.
-pub fn str_to_words(string: &str) -> Result<String, StrConversionError> {
-    use std::num::IntErrorKind;
+pub struct WordsOptions {
+    british: bool,
+    capitalize: bool,
+    negative_word: alloc::borrow::Cow<'static, str>,
+    point_word: alloc::borrow::Cow<'static, str>,
+    trim_fractional_zeros: bool,
+    hyphenate: bool,
+    trailing_point: bool,
+    integer_style: IntegerStyle,
+    fraction_style: FractionStyle,
+    oxford_comma: bool,
+    sign_position: SignPosition,
+}
+
+const DEFAULT_WORDS_OPTIONS: WordsOptions = WordsOptions {
+    british: false,
+    capitalize: false,
+    negative_word: alloc::borrow::Cow::Borrowed("negative"),
+    point_word: alloc::borrow::Cow::Borrowed("point"),
+    trim_fractional_zeros: false,
+    hyphenate: true,
+    trailing_point: true,
+    integer_style: IntegerStyle::Plain,
+    fraction_style: FractionStyle::Digits,
+    oxford_comma: false,
+    sign_position: SignPosition::Prefix,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents how the periods of a number are separated by [WordsOptions].
+pub enum IntegerStyle {
+    /// The periods are separated by spaces (***one thousand two hundred thirty-four***). This is the default.
+    Plain,
+    /// The periods are separated by commas (***one thousand, two hundred thirty-four***).
+    Commas,
+}
 
-    if string.len() == 0 {
-        return Ok("".to_string());
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents how the fractional part of a number is read by [WordsOptions::str_to_words].
+pub enum FractionStyle {
+    /// The digits are read individually after the decimal point (***point five six***). This is the default.
+    Digits,
+    /// The digits are read as a fraction over a power of ten (***and fifty-six hundredths***),
+    /// like in [str_to_words_decimal_denominator].
+    Denominator,
+}
+
+impl Default for WordsOptions {
+    fn default() -> Self {
+        DEFAULT_WORDS_OPTIONS
     }
+}
 
-    // Validity check
-    let mut decimal_point_flag = false;
-    let mut at_least_one_digit_flag = false;
-    for (i, byte) in string.bytes().enumerate() {
-        if byte == b'.' {
-            if decimal_point_flag {
-                return Err(StrConversionError::InvalidString);
-            }
-            decimal_point_flag = true;
-            continue;
-        }
-        if byte >= b'0' && byte <= b'9' {
-            at_least_one_digit_flag = true;
-        }
-        else if !(i == 0 && byte == b'-') {
-            return Err(StrConversionError::InvalidString);
+macro_rules! create_words_options_signed_int_func {
+    ($t:tt, $name:ident) => {
+        #[doc = concat!("Converts any `", stringify!($t), "` value to its **cardinal** number representation in words, using these options.")]
+        pub fn $name(&self, n: $t) -> String {
+            return self.i128_to_words(n as i128);
         }
+    };
+}
+
+impl WordsOptions {
+    /// Creates the default options (see [WordsOptions]).
+    pub fn new() -> Self {
+        DEFAULT_WORDS_OPTIONS
     }
-    if !at_least_one_digit_flag {
-        return Err(StrConversionError::InvalidString)
+
+    /// Sets whether ***and*** is used the British way, between the hundreds and the tens/ones of every
+    /// period and before a trailing period below one hundred (***two thousand and five***).
+    pub fn british(mut self, british: bool) -> Self {
+        self.british = british;
+        self
     }
 
-    let mut string = string;
+    /// Sets whether the first letter of the result is capitalized (see [capitalize_first]).
+    pub fn capitalize(mut self, capitalize: bool) -> Self {
+        self.capitalize = capitalize;
+        self
+    }
 
-    let mut words = Vec::<String>::new();
+    /// Sets the word put before negative numbers (e.g. `"minus"`). Both `&'static str` and
+    /// [`String`] are accepted, so the word can be chosen at runtime.
+    ///
+    /// # Example
+    /// ```
+    /// use num2en::WordsOptions;
+    ///
+    /// let options = WordsOptions::new().negative_word("minus");
+    /// assert_eq!(options.i32_to_words(-5), "minus five");
+    /// assert_eq!(options.str_to_words("-5"), Ok("minus five".to_string()));
+    ///
+    /// let word = String::from("below zero by");
+    /// assert_eq!(WordsOptions::new().negative_word(word).i32_to_words(-3), "below zero by three");
+    /// ```
+    pub fn negative_word(mut self, negative_word: impl Into<alloc::borrow::Cow<'static, str>>) -> Self {
+        self.negative_word = negative_word.into();
+        self
+    }
 
-    if string.bytes().nth(0).unwrap() == b'-' {
-        words.push("negative".to_string());
-        string = &string[1..];
+    /// Sets the word read for the decimal point (e.g. `"dot"` or `"comma"`). Both `&'static str`
+    /// and [`String`] are accepted.
+    ///
+    /// # Example
+    /// ```
+    /// use num2en::WordsOptions;
+    ///
+    /// let options = WordsOptions::new().point_word("dot");
+    /// assert_eq!(options.str_to_words("1.5"), Ok("one dot five".to_string()));
+    /// assert_eq!(options.str_to_words("2."), Ok("two dot".to_string()));
+    /// ```
+    pub fn point_word(mut self, point_word: impl Into<alloc::borrow::Cow<'static, str>>) -> Self {
+        self.point_word = point_word.into();
+        self
     }
 
-    let floating_point_index_option = string.find('.');
+    /// Sets whether trailing zeros of the fractional part are left out by [WordsOptions::str_to_words].
+    /// A fractional part of only zeros is left out together with the decimal point.
+    ///
+    /// # Example
+    /// ```
+    /// use num2en::WordsOptions;
+    ///
+    /// let options = WordsOptions::new().trim_fractional_zeros(true);
+    /// assert_eq!(options.str_to_words("34.500"), Ok("thirty-four point five".to_string()));
+    /// assert_eq!(options.str_to_words("0.050"), Ok("zero point zero five".to_string()));
+    /// assert_eq!(options.str_to_words("5.000"), Ok("five".to_string()));
+    /// ```
+    pub fn trim_fractional_zeros(mut self, trim_fractional_zeros: bool) -> Self {
+        self.trim_fractional_zeros = trim_fractional_zeros;
+        self
+    }
 
-    let integer_part_result = string[..floating_point_index_option.unwrap_or(string.len())].parse::<u128>();
+    /// Sets whether the tens and the ones of numbers between 21 and 99 are joined with a hyphen
+    /// (***twenty-one***) or a space (***twenty one***), which some screen readers pronounce better.
+    ///
+    /// # Example
+    /// ```
+    /// use num2en::WordsOptions;
+    ///
+    /// let options = WordsOptions::new().hyphenate(false);
+    /// assert_eq!(options.u128_to_words(21), "twenty one");
+    /// assert_eq!(options.u128_to_ord_words(21), "twenty first");
+    /// ```
+    pub fn hyphenate(mut self, hyphenate: bool) -> Self {
+        self.hyphenate = hyphenate;
+        self
+    }
 
-    match integer_part_result {
-        Err(parse_int_err) => {
-            match parse_int_err.kind() {
-                IntErrorKind::Empty => {},
-                IntErrorKind::InvalidDigit => unreachable!(),
-                IntErrorKind::NegOverflow => unreachable!(),
-                IntErrorKind::PosOverflow => {
-                    return Err(StrConversionError::TooLarge);
-                },
-                IntErrorKind::Zero => unreachable!(),
-                _ => unreachable!(),
-            }
-        },
-        Ok(integer_part) => {
-            words.push(u128_to_words(integer_part));
+    /// Sets whether [WordsOptions::str_to_words] reads a decimal point that has no digits after it
+    /// (***ninety-five point***). When set to `false`, only the integer part is read.
+    ///
+    /// # Example
+    /// ```
+    /// use num2en::WordsOptions;
+    ///
+    /// let options = WordsOptions::new().trailing_point(false);
+    /// assert_eq!(options.str_to_words("-1095."), Ok("negative one thousand ninety-five".to_string()));
+    /// assert_eq!(options.str_to_words("1095.0"), Ok("one thousand ninety-five point zero".to_string()));
+    /// ```
+    pub fn trailing_point(mut self, trailing_point: bool) -> Self {
+        self.trailing_point = trailing_point;
+        self
+    }
+
+    /// Sets how the periods of the integer part are separated, independently of how the fractional
+    /// part is read (see [WordsOptions::fraction_style]).
+    ///
+    /// # Example
+    /// ```
+    /// use num2en::{IntegerStyle, WordsOptions};
+    ///
+    /// let options = WordsOptions::new().integer_style(IntegerStyle::Commas);
+    /// assert_eq!(options.u128_to_words(2_000_345), "two million, three hundred forty-five");
+    /// ```
+    pub fn integer_style(mut self, integer_style: IntegerStyle) -> Self {
+        self.integer_style = integer_style;
+        self
+    }
+
+    /// Sets how the fractional part is read by [WordsOptions::str_to_words], independently of how the
+    /// integer part is spelled (see [WordsOptions::integer_style]).
+    ///
+    /// # Example
+    /// ```
+    /// use num2en::{FractionStyle, IntegerStyle, WordsOptions};
+    ///
+    /// let options = WordsOptions::new().british(true).integer_style(IntegerStyle::Commas);
+    /// let words = options.clone().fraction_style(FractionStyle::Digits).str_to_words("1,234.56");
+    /// assert_eq!(words, Ok("one thousand, two hundred and thirty-four point five six".to_string()));
+    ///
+    /// let words = options.fraction_style(FractionStyle::Denominator).str_to_words("1,234.56");
+    /// assert_eq!(words, Ok("one thousand, two hundred and thirty-four and fifty-six hundredths".to_string()));
+    /// ```
+    ///
+    /// # Notes
+    /// - With [FractionStyle::Denominator], a fractional part with more than 38 digits results in a
+    ///   [TooLarge](StrConversionError::TooLarge) error.
+    pub fn fraction_style(mut self, fraction_style: FractionStyle) -> Self {
+        self.fraction_style = fraction_style;
+        self
+    }
+
+    /// Sets whether a comma is also placed before the British ***and*** of a trailing period when the
+    /// periods are separated by commas ([IntegerStyle::Commas]). The default leaves it out, as in UK usage.
+    ///
+    /// # Example
+    /// ```
+    /// use num2en::{IntegerStyle, WordsOptions};
+    ///
+    /// let options = WordsOptions::new().british(true).integer_style(IntegerStyle::Commas);
+    /// assert_eq!(options.u128_to_words(2_000_005), "two million and five");
+    /// assert_eq!(options.oxford_comma(true).u128_to_words(2_000_005), "two million, and five");
+    /// ```
+    pub fn oxford_comma(mut self, oxford_comma: bool) -> Self {
+        self.oxford_comma = oxford_comma;
+        self
+    }
+
+    /// Sets whether the sign word (see [WordsOptions::negative_word]) is placed before or after the
+    /// number, including its fractional part.
+    ///
+    /// # Example
+    /// ```
+    /// use num2en::{SignPosition, WordsOptions};
+    ///
+    /// let options = WordsOptions::new().negative_word("minus").sign_position(SignPosition::Suffix);
+    /// assert_eq!(options.i32_to_words(-42), "forty-two minus");
+    /// assert_eq!(options.str_to_words("-1000.5"), Ok("one thousand point five minus".to_string()));
+    /// ```
+    pub fn sign_position(mut self, sign_position: SignPosition) -> Self {
+        self.sign_position = sign_position;
+        self
+    }
+
+    /// Converts any `u128` value to its **cardinal** number representation in words, using these options.
+    #[allow(clippy::needless_return)]
+    pub fn u128_to_words(&self, n: u128) -> String {
+        return self.finish(self.spell_u128(n));
+    }
+
+    /// Converts any `i128` value to its **cardinal** number representation in words, using these options.
+    #[allow(clippy::needless_return)]
+    pub fn i128_to_words(&self, n: i128) -> String {
+        let mut words = vec![self.spell_u128(n.unsigned_abs())];
+        if n < 0 {
+            push_sign(&mut words, self);
         }
+        return self.finish(words.join(" "));
     }
 
-    if let Some(floating_point_index) = floating_point_index_option {
-        words.push("point".to_string());
-        if floating_point_index < string.len() - 1 {
-            let decimal_part = &string[floating_point_index + 1..];
-            words.push(str_digits_to_words(decimal_part).unwrap());
+    /// Converts any `u128` value to its **ordinal** number representation in words, using these options.
+    #[allow(clippy::needless_return)]
+    pub fn u128_to_ord_words(&self, n: u128) -> String {
+        if n == 0 {
+            return self.finish("zeroth".to_string());
         }
+        let mut words = self.spell_u128(n).split(' ').map(|word| word.to_string()).collect::<Vec<_>>();
+        ordinalize_last(&mut words);
+        return self.finish(words.join(" "));
     }
 
-    return Ok(words.join(" "));
-}
+    create_words_options_signed_int_func!(i8, i8_to_words);
+    create_words_options_signed_int_func!(i16, i16_to_words);
+    create_words_options_signed_int_func!(i32, i32_to_words);
+    create_words_options_signed_int_func!(i64, i64_to_words);
+    create_words_options_signed_int_func!(isize, isize_to_words);
 
+    /// Converts any* string of a (decimal) number to words, using these options.
+    /// <br> * The same restrictions as in [str_to_words] apply.
+    #[allow(clippy::needless_return)]
+    pub fn str_to_words(&self, string: &str) -> Result<String, StrConversionError> {
+        return self.number_str_to_words(&parse_number_str(string)?);
+    }
 
-#[derive(Debug, PartialEq)]
-/// Represents the possible errors that can occur when calling [f32_to_words] or [f64_to_words].
-pub enum FloatConversionError {
-    /// Indicates that the value is not finite (i.e., it is either `NaN`, positive infinity, or negative infinity).
-    NotFinite,
-    /// Indicates that the value is too large to be converted.
-    TooLarge,
-}
+    #[allow(clippy::needless_return)]
+    fn number_str_to_words(&self, number: &NumberStr) -> Result<String, StrConversionError> {
+        let mut fractional_part = number.fractional_part.as_deref();
+        if self.trim_fractional_zeros {
+            fractional_part = fractional_part
+                .map(|digits| digits.trim_end_matches('0'))
+                .filter(|digits| !digits.is_empty());
+        }
+        else if !self.trailing_point {
+            fractional_part = fractional_part.filter(|digits| !digits.is_empty());
+        }
 
-macro_rules! create_public_conversion_func_of_float {
-    ( $t:ty, $name:ident ) => {
-        /// Converts any*
-        #[doc = concat!("`", stringify!($t), "`")]
-        /// value of a number to a number representation in words.
-        ///
-        /// # Arguments
-        /// - `float`: A float
-        #[doc = concat!("(`", stringify!($t), "`)")]
-        /// that represents the number to be converted.
-        /// <br> * The number must be 2<sup>128</sup> - 1 (~ 340 undecillion) or smaller,
-        /// otherwise a [TooLarge](FloatConversionError::TooLarge) error gets returned.
-        ///
-        /// # Returns
-        /// [`Result`]`<`[`String`]`, `[`FloatConversionError`]`>`
-        /// 
-        /// The string contains the English words that represent the input number.
-        /// 
-        /// For example, `"123.456"` becomes `"one hundred twenty-three point four five six"`.
-        ///
-        #[doc = concat!(
-            "# Examples\n\
-            ```\n\
-            use num2en::", stringify!($name), ";\n\
-            # use num2en::FloatConversionError;\n\n\
-            let number = 123.123;\n\
-            let result = ", stringify!($name), "(number);\n\
-            assert_eq!(result, Ok(\"one hundred twenty-three point one two three\".to_string()));\n\n\
-            let number = 4e-5;\n\
-            let result = ", stringify!($name), "(number);\n\
-            assert_eq!(result, Ok(\"zero point zero zero zero zero four\".to_string()));\n\n\
-            let number = 34.000;\n\
-            let result = ", stringify!($name), "(number);\n\
-            assert_eq!(result, Ok(\"thirty-four\".to_string()));\n\n\
-            let infinity = ", stringify!($t), "::INFINITY;\n\
-            let result = ", stringify!($name), "(infinity);\n\
-            assert_eq!(result, Err(FloatConversionError::NotFinite));\n\n\
-            let not_a_number = ", stringify!($t), "::NAN;\n\
-            let result = ", stringify!($name), "(not_a_number);\n\
-            assert_eq!(result, Err(FloatConversionError::NotFinite));\n\
-            ```"
-        )]
-        /// 
-        /// # Notes
-        /// - This function supports only numbers between `-u128::MAX-1` (exclusive) and `u128::MAX+1` (exclusive).
-        /// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-one").
-        /// - This function uses [str_to_words] behind the curtains.
-        pub fn $name(float: $t) -> Result<String, FloatConversionError> {
-            if !float.is_finite() {
-                return Err(FloatConversionError::NotFinite);
+        if self.fraction_style == FractionStyle::Denominator {
+            let integer_part = number.integer_part.unwrap_or(0);
+            return self.denominator_fraction_words(number.negative, integer_part, fractional_part.unwrap_or(""));
+        }
+
+        let mut words = Vec::<String>::new();
+        match number.integer_part {
+            Some(integer_part) => words.push(self.spell_u128(integer_part)),
+            // ".000" is trimmed to nothing
+            None if number.fractional_part.is_some() && fractional_part.is_none() => words.push("zero".to_string()),
+            None => {},
+        }
+        if let Some(fractional_part) = fractional_part {
+            words.push(self.point_word.to_string());
+            if !fractional_part.is_empty() {
+                words.push(str_digits_to_words(fractional_part).unwrap());
+            }
+        }
+        if number.negative {
+            push_sign(&mut words, self);
+        }
+
+        return Ok(self.finish(words.join(" ")));
+    }
+
+    #[allow(clippy::needless_return)]
+    fn denominator_fraction_words(&self, negative: bool, integer_part: u128, fractional_part: &str) -> Result<String, StrConversionError> {
+        let mut numerator = 0;
+        let mut denominator = 1;
+        if !fractional_part.is_empty() {
+            numerator = parse_digits(fractional_part)?;
+            denominator = 10u128.checked_pow(fractional_part.len() as u32).ok_or(StrConversionError::TooLarge)?;
+        }
+
+        let mut words = Vec::<String>::new();
+        if integer_part != 0 || numerator == 0 {
+            words.push(self.spell_u128(integer_part));
+        }
+        if numerator != 0 {
+            if integer_part != 0 {
+                words.push("and".to_string());
             }
+            words.push(self.spell_u128(numerator) + " " + &denominator_words(denominator, numerator != 1));
+        }
+        if negative {
+            push_sign(&mut words, self);
+        }
+
+        return Ok(self.finish(words.join(" ")));
+    }
+
+    /// Converts any finite `f64` value to words, rounded to exactly `decimals` fractional digits,
+    /// using these options.
+    /// <br> See [f64_to_words_prec] for the details.
+    #[allow(clippy::needless_return)]
+    pub fn f64_to_words_prec(&self, f: f64, decimals: usize) -> Result<String, FloatConversionError> {
+        // the smallest subnormal f64 (2^-1074) has 1074 fractional digits
+        const MAX_F64_DECIMALS: usize = 1074;
 
-            let float_string = float.to_string();
+        if !f.is_finite() {
+            return Err(FloatConversionError::NotFinite);
+        }
+        let float_string = format!("{:.*}", decimals.min(MAX_F64_DECIMALS), f);
+        return float_str_to_words(&float_string, f.is_sign_negative(), SignedZero::Suppress, self);
+    }
 
-            match str_to_words(&float_string) {
-                Err(StrConversionError::TooLarge) => return Err(FloatConversionError::TooLarge),
-                Err(StrConversionError::InvalidString) => unreachable!(),
-                Ok(words) => return Ok(words),
+    /// Converts a power (`base` raised to `exponent`) to words, using these options.
+    /// <br> See [power_to_words_with] for the details.
+    ///
+    /// # Example
+    /// ```
+    /// use num2en::{PowerStyle, WordsOptions};
+    ///
+    /// let options = WordsOptions::new().negative_word("minus");
+    /// assert_eq!(options.power_to_words(10, -3, PowerStyle::Ordinal), "ten to the minus third");
+    /// ```
+    ///
+    /// # Notes
+    /// - With [PowerStyle::Per], the ***one*** of a reciprocal that is a power of ten is left out
+    ///   (***per million***). A reciprocal that is not positive or does not fit in a `u128` is read
+    ///   like with [PowerStyle::Ordinal].
+    #[allow(clippy::needless_return)]
+    pub fn power_to_words(&self, base: i128, exponent: i128, style: PowerStyle) -> String {
+        let options = self.clone().capitalize(false);
+
+        if style == PowerStyle::Per && exponent < 0 && base > 0 {
+            let reciprocal = u32::try_from(exponent.unsigned_abs()).ok()
+                .and_then(|exponent| (base as u128).checked_pow(exponent));
+            if let Some(reciprocal) = reciprocal {
+                let reciprocal_words = options.u128_to_words(reciprocal);
+                let reciprocal_words = match reciprocal_words.strip_prefix("one ") {
+                    Some(period) if !period.contains(' ') => period,
+                    _ => &reciprocal_words,
+                };
+                return self.finish("per ".to_string() + reciprocal_words);
             }
         }
+
+        let base_words = options.i128_to_words(base);
+        if exponent == 1 {
+            return self.finish(base_words);
+        }
+
+        let mut words = vec![base_words, "to the".to_string()];
+        if exponent < 0 {
+            words.push(self.negative_word.to_string());
+        }
+        words.push(options.u128_to_ord_words(exponent.unsigned_abs()));
+        return self.finish(words.join(" "));
+    }
+
+    #[allow(clippy::needless_return)]
+    fn spell_u128(&self, n: u128) -> String {
+        let mut groups = period_groups(n, self);
+        let separator = match self.integer_style {
+            IntegerStyle::Plain => " ",
+            IntegerStyle::Commas => ", ",
+        };
+        let last_group = n % 1000;
+        if self.british && n >= 1000 && last_group != 0 && last_group < 100 {
+            let last = groups.pop().unwrap();
+            let and_separator = if self.oxford_comma { separator } else { " " };
+            return groups.join(separator) + and_separator + "and " + &last;
+        }
+        return groups.join(separator);
+    }
+
+    #[allow(clippy::needless_return)]
+    fn finish(&self, words: String) -> String {
+        if self.capitalize {
+            return capitalize_first(&words);
+        }
+        return words;
+    }
+}
+
+/// Spoken abbreviations of the period names, for use with [u128_to_abbreviated_words].
+pub const SPOKEN_SCALE_ABBREVIATIONS: [&str; 4] = ["K", "mil", "bil", "tril"];
+
+/// Converts any `u128` value to a casual spoken form with a spelled mantissa and a clipped scale
+/// word (***one point five mil***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+/// - `decimals`: The largest number of fractional digits of the mantissa to be read.
+/// - `scale_words`: The words used instead of ***thousand***, ***million***, ***billion***, ... in
+///   that order (e.g. [SPOKEN_SCALE_ABBREVIATIONS]). Periods past the end of the table use their full names.
+///
+/// # Returns
+/// A [`String`] containing the number expressed in its largest period: the mantissa read with
+/// ***point*** (without trailing zeros), followed by the scale word.
+/// <br> Numbers below one thousand are read as ordinary cardinals.
+///
+/// # Examples
+/// ```
+/// use num2en::{u128_to_abbreviated_words, SPOKEN_SCALE_ABBREVIATIONS};
+///
+/// let words = u128_to_abbreviated_words(1_500_000, 1, &SPOKEN_SCALE_ABBREVIATIONS);
+/// assert_eq!(words, "one point five mil");
+///
+/// let words = u128_to_abbreviated_words(12_345, 2, &SPOKEN_SCALE_ABBREVIATIONS);
+/// assert_eq!(words, "twelve point three four K");
+///
+/// let words = u128_to_abbreviated_words(3_000_000_000, 1, &["grand", "mil", "bil"]);
+/// assert_eq!(words, "three bil");
+/// ```
+///
+/// # Notes
+/// - The mantissa is truncated, not rounded, so it never carries over into the next period.
+#[allow(clippy::needless_return)]
+pub fn u128_to_abbreviated_words(n: u128, decimals: usize, scale_words: &[&str]) -> String {
+    if n < 1000 {
+        return u128_to_words(n);
+    }
+
+    let mut period = 0;
+    let mut divisor = 1;
+    while n / divisor >= 1000 {
+        divisor *= 1000;
+        period += 1;
+    }
+
+    let mut words = vec![u128_to_words(n / divisor)];
+
+    let fraction = format!("{:0width$}", n % divisor, width = 3 * period);
+    let fraction = fraction[..decimals.min(fraction.len())].trim_end_matches('0');
+    if !fraction.is_empty() {
+        words.push("point".to_string());
+        words.push(str_digits_to_words(fraction).unwrap());
+    }
+
+    words.push(scale_words.get(period - 1).unwrap_or(&PERIODS[period - 1]).to_string());
+
+    return words.join(" ");
+}
+
+/// Converts any `u128` value to words after rounding it to a number of significant figures, read
+/// loosely (***about two million***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+/// - `sig_figs`: The number of significant figures to keep. Zero is treated as one.
+///
+/// # Returns
+/// A [`String`] containing the rounded number in words, preceded by ***about*** if rounding changed
+/// the value.
+///
+/// # Example
+/// ```
+/// use num2en::u128_to_approx_words;
+///
+/// assert_eq!(u128_to_approx_words(2_345_678, 1), "about two million");
+/// assert_eq!(u128_to_approx_words(2_345_678, 2), "about two million three hundred thousand");
+/// assert_eq!(u128_to_approx_words(999_999, 2), "about one million");
+/// assert_eq!(u128_to_approx_words(3_000_000, 1), "three million");
+/// assert_eq!(u128_to_approx_words(2_345_678, 0), "about two million");
+/// ```
+///
+/// # Notes
+/// - Halves are rounded up. If rounding up would overflow `u128`, the value is rounded down instead.
+#[allow(clippy::needless_return)]
+pub fn u128_to_approx_words(n: u128, sig_figs: u8) -> String {
+    let sig_figs = sig_figs.max(1);
+
+    let mut digits = 1;
+    while digits < 39 && n >= 10u128.pow(digits) {
+        digits += 1;
+    }
+    if digits <= sig_figs as u32 {
+        return u128_to_words(n);
+    }
+
+    let scale = 10u128.pow(digits - sig_figs as u32);
+    let truncated = n / scale;
+    let rounded = if n % scale >= scale / 2 {
+        (truncated + 1).checked_mul(scale).unwrap_or(truncated * scale)
+    } else {
+        truncated * scale
+    };
+
+    if rounded == n {
+        return u128_to_words(n);
+    }
+    return "about ".to_string() + &u128_to_words(rounded);
+}
+
+/// Converts any `u128` value to its **cardinal** number representation in words, using a custom
+/// grouping of digits and custom group names (e.g. the Indian lakh and crore).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+/// - `group_sizes`: The number of digits in each group, starting with the least significant group.
+///   The last size is repeated for all further groups.
+/// - `group_names`: The names of the groups, starting with the second least significant group
+///   (the least significant group has no name).
+///
+/// # Returns
+/// A [`String`] containing each non-zero group spelled out and followed by its name.
+/// <br> The most significant named group takes all the remaining digits, and a value of 1000 or more
+/// in it is spelled with the same grouping (e.g. ***one lakh crore***). Values of 1000 or more in any
+/// other group are spelled like by [u128_to_words].
+///
+/// # Errors
+/// Returns [GroupingError::InvalidGroupSizes] if `group_sizes` is empty or contains a zero.
+///
+/// # Examples
+/// ```
+/// use num2en::{to_words_with_groups, u128_to_words, GroupingError};
+///
+/// // Western grouping
+/// let periods = ["thousand", "million", "billion", "trillion", "quadrillion", "quintillion",
+///     "sextillion", "septillion", "octillion", "nonillion", "decillion", "undecillion"];
+/// assert_eq!(to_words_with_groups(1_234_567, &[3], &periods), Ok(u128_to_words(1_234_567)));
+///
+/// // Indian grouping
+/// let words = to_words_with_groups(123_456_789, &[3, 2], &["thousand", "lakh", "crore"]).unwrap();
+/// assert_eq!(words, "twelve crore thirty-four lakh fifty-six thousand seven hundred eighty-nine");
+///
+/// let words = to_words_with_groups(1_000_000_000_000, &[3, 2], &["thousand", "lakh", "crore"]).unwrap();
+/// assert_eq!(words, "one lakh crore");
+///
+/// assert_eq!(to_words_with_groups(5, &[3, 0], &["thousand"]), Err(GroupingError::InvalidGroupSizes));
+/// ```
+///
+/// # Notes
+/// - [u128_to_words] and the other Western conversions use the same grouping with `&[3]`.
+#[allow(clippy::needless_return)]
+pub fn to_words_with_groups(n: u128, group_sizes: &[usize], group_names: &[&str]) -> Result<String, GroupingError> {
+    if group_sizes.is_empty() || group_sizes.contains(&0) {
+        return Err(GroupingError::InvalidGroupSizes);
+    }
+    return Ok(grouped_words(n, group_sizes, group_names));
+}
+
+/// [to_words_with_groups] for `group_sizes` known to be valid.
+#[allow(clippy::needless_return)]
+fn grouped_words(n: u128, group_sizes: &[usize], group_names: &[&str]) -> String {
+    if n == 0 {
+        return "zero".to_string();
+    }
+
+    let mut words = Vec::<String>::new();
+    push_grouped_words(n, group_sizes, group_names, &mut words);
+    return words.join(" ");
+}
+
+fn push_grouped_words(n: u128, group_sizes: &[usize], group_names: &[&str], words: &mut Vec<String>) {
+    for_each_digit_group(n, group_sizes, group_names.len(), |group, i| {
+        if group < 1000 {
+            lt1000(group as u16, &DEFAULT_WORDS_OPTIONS, words);
+        }
+        else if i > 0 && i == group_names.len() {
+            push_grouped_words(group, group_sizes, group_names, words);
+        }
+        else {
+            words.push(u128_to_words(group));
+        }
+        if i > 0 {
+            words.push(group_names[i - 1].to_string());
+        }
+    });
+}
+
+#[derive(Debug, PartialEq)]
+/// Represents the possible error that can occur when calling [to_words_with_groups].
+pub enum GroupingError {
+    /// Indicates that the group sizes are empty or contain a zero.
+    InvalidGroupSizes,
+}
+
+impl GroupingError {
+    /// Returns a short human-readable description of the error.
+    pub fn message(&self) -> &'static str {
+        match self {
+            GroupingError::InvalidGroupSizes => "group sizes must be non-empty and non-zero",
+        }
+    }
+}
+
+impl core::fmt::Display for GroupingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GroupingError {}
+
+/// Names of the myriad groups (10<sup>4k</sup>) used by [u128_to_myriad_words]. Each name is the
+/// English reading of the group's value, mirroring 万, 亿, 兆, 京, ...
+pub const MYRIAD_GROUP_NAMES: [&str; 9] = [
+    "ten-thousand", "hundred-million", "trillion", "ten-quadrillion", "hundred-quintillion",
+    "septillion", "ten-octillion", "hundred-nonillion", "undecillion",
+];
+
+/// Converts any `u128` value to its **cardinal** number representation in words, grouping the
+/// digits by four like Chinese and Japanese numerals do (myriads).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`String`] containing each non-zero group of four digits spelled out and followed by its name
+/// from [MYRIAD_GROUP_NAMES] (***ten-thousand***, ***hundred-million***, ***trillion***, ...).
+///
+/// # Example
+/// ```
+/// use num2en::u128_to_myriad_words;
+///
+/// let words = u128_to_myriad_words(123_456_789);
+/// assert_eq!(
+///     words,
+///     "one hundred-million two thousand three hundred forty-five ten-thousand six thousand seven hundred eighty-nine"
+/// );
+///
+/// assert_eq!(u128_to_myriad_words(50_000), "five ten-thousand");
+/// ```
+///
+/// # Notes
+/// - This function uses [to_words_with_groups] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn u128_to_myriad_words(n: u128) -> String {
+    return grouped_words(n, &[4], &MYRIAD_GROUP_NAMES);
+}
+
+/// Names of the periods (10<sup>3k</sup>) in the long scale used by [u128_to_words_long_scale], where
+/// ***billion*** is 10<sup>12</sup> and the periods in between take the ***-ard*** names.
+pub const PERIODS_LONG_SCALE: [&str; 12] = [
+    "thousand", "million", "milliard", "billion", "billiard", "trillion",
+    "trilliard", "quadrillion", "quadrilliard", "quintillion", "quintilliard", "sextillion",
+];
+
+/// Converts any `u128` value to its **cardinal** number representation in words, using the long
+/// scale (***million***, ***milliard***, ***billion***, ***billiard***, ...) common in Europe.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`String`] containing the same words as [u128_to_words], except that the period names are
+/// taken from [PERIODS_LONG_SCALE].
+///
+/// # Example
+/// ```
+/// use num2en::u128_to_words_long_scale;
+///
+/// assert_eq!(u128_to_words_long_scale(2_000_000_000), "two milliard");
+/// assert_eq!(u128_to_words_long_scale(3_000_000_000_001), "three billion one");
+/// ```
+///
+/// # Notes
+/// - This function uses [to_words_with_groups] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn u128_to_words_long_scale(n: u128) -> String {
+    return grouped_words(n, &[3], &PERIODS_LONG_SCALE);
+}
+
+/// Names of the Indian numbering groups used by [u128_to_words_indian]: ***thousand*** (10<sup>3</sup>),
+/// then every hundredfold from ***lakh*** (10<sup>5</sup>) to ***shankh*** (10<sup>17</sup>).
+pub const INDIAN_GROUP_NAMES: [&str; 8] = [
+    "thousand", "lakh", "crore", "arab", "kharab", "nil", "padma", "shankh",
+];
+
+/// Converts any `u128` value to its **cardinal** number representation in words, grouping the
+/// digits like the Indian numbering system does (3 digits, then 2 digits per group).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`String`] containing each non-zero group spelled out and followed by its name from
+/// [INDIAN_GROUP_NAMES] (***thousand***, ***lakh***, ***crore***, ***arab***, ...).
+///
+/// # Example
+/// ```
+/// use num2en::u128_to_words_indian;
+///
+/// let words = u128_to_words_indian(123_456);
+/// assert_eq!(words, "one lakh twenty-three thousand four hundred fifty-six");
+///
+/// assert_eq!(u128_to_words_indian(50_000_000), "five crore");
+/// ```
+///
+/// # Notes
+/// - The largest group name is ***shankh*** (10<sup>17</sup>). From 1000 shankh on, the number of
+///   shankhs is itself spelled with Indian grouping (***one lakh shankh***), so the whole `u128`
+///   range is supported.
+/// - This function uses [to_words_with_groups] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn u128_to_words_indian(n: u128) -> String {
+    return grouped_words(n, &[3, 2], &INDIAN_GROUP_NAMES);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents the spelling conventions used by [u128_to_words_locale].
+pub enum Locale {
+    /// American English, as in [u128_to_words] (***one thousand two hundred thirty-four***).
+    American,
+    /// British English with ***and***, as in [u128_to_words_british] (***one thousand two hundred and thirty-four***).
+    BritishWithAnd,
+    /// Indian English with lakhs and crores, as in [u128_to_words_indian] (***twelve lakh thirty-four thousand***).
+    Indian,
+}
+
+/// Converts any `u128` value to its **cardinal** number representation in words, using the
+/// conventions of `locale`.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+/// - `locale`: The [Locale] whose grouping and use of ***and*** are followed.
+///
+/// # Returns
+/// A [`String`] containing the English words that represent the input cardinal number.
+///
+/// # Example
+/// ```
+/// use num2en::{u128_to_words_locale, Locale};
+///
+/// assert_eq!(u128_to_words_locale(105, Locale::American), "one hundred five");
+/// assert_eq!(u128_to_words_locale(105, Locale::BritishWithAnd), "one hundred and five");
+/// assert_eq!(u128_to_words_locale(100_000, Locale::Indian), "one lakh");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn u128_to_words_locale(n: u128, locale: Locale) -> String {
+    return match locale {
+        Locale::American => u128_to_words(n),
+        Locale::BritishWithAnd => u128_to_words_british(n),
+        Locale::Indian => u128_to_words_indian(n),
     };
 }
 
-create_public_conversion_func_of_float!(f32, f32_to_words);
-create_public_conversion_func_of_float!(f64, f64_to_words);
+/// Converts any `u128` value to its **cardinal** number representation as a list of words (tokens).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`Vec`]`<`[`String`]`>` of the words [u128_to_words] would join with spaces.
+/// <br> Hyphenated compounds (e.g. ***twenty-one***) are kept as a single token.
+///
+/// # Example
+/// ```
+/// use num2en::u128_to_words_tokens;
+///
+/// assert_eq!(u128_to_words_tokens(1021), vec!["one", "thousand", "twenty-one"]);
+/// ```
+pub fn u128_to_words_tokens(n: u128) -> Vec<String> {
+    u128_to_words(n).split(' ').map(|word| word.to_string()).collect()
+}
+
+/// Converts any `u128` value to its **cardinal** number representation as a list of words (tokens)
+/// in reverse order.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`Vec`]`<`[`String`]`>` which is the reverse of [u128_to_words_tokens].
+/// <br> Hyphenated compounds (e.g. ***twenty-one***) are kept as a single token and are not reversed themselves.
+///
+/// # Example
+/// ```
+/// use num2en::u128_to_words_tokens_rev;
+///
+/// assert_eq!(u128_to_words_tokens_rev(1021), vec!["twenty-one", "thousand", "one"]);
+/// ```
+#[allow(clippy::needless_return)]
+pub fn u128_to_words_tokens_rev(n: u128) -> Vec<String> {
+    let mut tokens = u128_to_words_tokens(n);
+    tokens.reverse();
+    return tokens;
+}
 
+/// Returns a lazy iterator over the words of any `u128` value's **cardinal** number representation.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`U128WordsIter`] yielding the same words as [u128_to_words_tokens], one period at a time.
+/// <br> Words are borrowed from static tables; only hyphenated compounds (e.g. ***twenty-one***) are
+/// yielded as owned strings.
+///
+/// # Example
+/// ```
+/// use num2en::u128_words_iter;
+///
+/// let words: Vec<_> = u128_words_iter(1_123).collect();
+/// assert_eq!(words, vec!["one", "thousand", "one", "hundred", "twenty-three"]);
+/// ```
+#[allow(clippy::needless_return)]
+pub fn u128_words_iter(n: u128) -> U128WordsIter {
+    let mut iter = U128WordsIter {
+        n,
+        divisor: 1000u128.pow(U128_PERIODS as u32),
+        period_idx: U128_PERIODS,
+        pending: Default::default(),
+        pending_len: 0,
+        pending_pos: 0,
+    };
+    if n == 0 {
+        iter.pending[0] = Some(alloc::borrow::Cow::Borrowed("zero"));
+        iter.pending_len = 1;
+        iter.divisor = 0;
+    }
+    return iter;
+}
+
+#[derive(Debug, Clone)]
+/// Iterator over the words of a number, returned by [u128_words_iter].
+pub struct U128WordsIter {
+    n: u128,
+    /// `10 ** 3k` of the next period to be spelled, `0` when all periods are done
+    divisor: u128,
+    period_idx: usize,
+    /// words of the current period (at most ***x hundred yy period***)
+    pending: [Option<alloc::borrow::Cow<'static, str>>; 4],
+    pending_len: usize,
+    pending_pos: usize,
+}
+
+impl U128WordsIter {
+    fn push_pending(&mut self, word: alloc::borrow::Cow<'static, str>) {
+        self.pending[self.pending_len] = Some(word);
+        self.pending_len += 1;
+    }
+
+    #[allow(clippy::needless_return)]
+    fn lt100_word(n: u8) -> alloc::borrow::Cow<'static, str> {
+        if n < 20 {
+            return alloc::borrow::Cow::Borrowed(NUMS_SMALLER_THAN_20[n as usize - NUMS_SMALLER_THAN_20_OFFSET]);
+        }
+        if n % 10 == 0 {
+            return alloc::borrow::Cow::Borrowed(MULTIPLES_OF_10[(n / 10) as usize - MULTIPLES_OF_10_OFFSET]);
+        }
+        let mut word = String::new();
+        write_lt100(n, &DEFAULT_WORDS_OPTIONS, &mut word);
+        return alloc::borrow::Cow::Owned(word);
+    }
+
+    /// Fills `pending` with the words of the next non-zero period. Returns `false` if there is none.
+    #[allow(clippy::needless_return)]
+    fn next_period(&mut self) -> bool {
+        while self.divisor != 0 {
+            let divisor = self.divisor;
+            self.divisor /= 1000;
+            let group = ((self.n / divisor) % 1000) as u16;
+            if divisor >= 1000 {
+                self.period_idx -= 1;
+            }
+            if group == 0 {
+                continue;
+            }
+
+            self.pending_len = 0;
+            self.pending_pos = 0;
+            if group >= 100 {
+                self.push_pending(Self::lt100_word((group / 100) as u8));
+                self.push_pending(alloc::borrow::Cow::Borrowed("hundred"));
+            }
+            if group % 100 != 0 {
+                self.push_pending(Self::lt100_word((group % 100) as u8));
+            }
+            if divisor >= 1000 {
+                self.push_pending(alloc::borrow::Cow::Borrowed(PERIODS[self.period_idx]));
+            }
+            return true;
+        }
+        return false;
+    }
+}
+
+impl Iterator for U128WordsIter {
+    type Item = alloc::borrow::Cow<'static, str>;
+
+    #[allow(clippy::needless_return)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pending_pos == self.pending_len && !self.next_period() {
+            return None;
+        }
+        self.pending_pos += 1;
+        return self.pending[self.pending_pos - 1].take();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents the semantic role of a token returned by [u128_to_words_spans].
+pub enum TokenKind {
+    /// ***zero*** to ***nine***
+    Digit,
+    /// ***ten*** to ***nineteen*** and the multiples of ten (***twenty*** to ***ninety***)
+    Tens,
+    /// ***hundred***
+    Hundred,
+    /// The name of a period (***thousand***, ***million***, ...)
+    Period,
+    /// The hyphen joining a multiple of ten and a digit (***twenty-one***)
+    Connector,
+}
+
+/// Converts any `u128` value to its **cardinal** number representation in words, together with the
+/// byte range and [`TokenKind`] of each token.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`String`] containing the same words as [u128_to_words], and a
+/// [`Vec`]`<(`[`Range`](core::ops::Range)`<usize>, `[`TokenKind`]`)>` with one entry per token, in order.
+/// <br> The ranges index into the returned string. Spaces between words are not covered by any range.
+///
+/// # Example
+/// ```
+/// use num2en::{u128_to_words_spans, TokenKind};
+///
+/// let (words, spans) = u128_to_words_spans(1_021);
+/// assert_eq!(words, "one thousand twenty-one");
+/// assert_eq!(spans, vec![
+///     (0..3, TokenKind::Digit),
+///     (4..12, TokenKind::Period),
+///     (13..19, TokenKind::Tens),
+///     (19..20, TokenKind::Connector),
+///     (20..23, TokenKind::Digit),
+/// ]);
+/// ```
+#[allow(clippy::needless_return)]
+pub fn u128_to_words_spans(n: u128) -> (String, Vec<(core::ops::Range<usize>, TokenKind)>) {
+    let mut words = String::new();
+    let mut spans = Vec::new();
+
+    if n == 0 {
+        push_span("zero", TokenKind::Digit, &mut words, &mut spans);
+        return (words, spans);
+    }
+
+    for_each_digit_group(n, &[3], PERIODS.len(), |group, idx| {
+        let group = group as u16;
+        if group >= 100 {
+            push_lt100_spans((group / 100) as u8, &mut words, &mut spans);
+            push_span("hundred", TokenKind::Hundred, &mut words, &mut spans);
+        }
+        if group % 100 != 0 {
+            push_lt100_spans((group % 100) as u8, &mut words, &mut spans);
+        }
+        if idx > 0 {
+            push_span(PERIODS[idx - 1], TokenKind::Period, &mut words, &mut spans);
+        }
+    });
+
+    return (words, spans);
+}
+
+/// Appends `word` to `words`, preceded by a space if `words` is not empty, and records its span.
+fn push_span(word: &str, kind: TokenKind, words: &mut String, spans: &mut Vec<(core::ops::Range<usize>, TokenKind)>) {
+    write_word_separator(words, 0);
+    let start = words.len();
+    words.push_str(word);
+    spans.push((start..words.len(), kind));
+}
+
+/// Appends the word of `n` (between 1 and 99) to `words` like [write_lt100], recording the span of
+/// each part of a hyphenated compound.
+fn push_lt100_spans(n: u8, words: &mut String, spans: &mut Vec<(core::ops::Range<usize>, TokenKind)>) {
+    if n < 10 {
+        push_span(word_below_20(n), TokenKind::Digit, words, spans);
+    }
+    else if n < 20 || n % 10 == 0 {
+        push_span(if n < 20 { word_below_20(n) } else { multiple_of_ten(n / 10) }, TokenKind::Tens, words, spans);
+    }
+    else {
+        push_span(multiple_of_ten(n / 10), TokenKind::Tens, words, spans);
+        let start = words.len();
+        words.push('-');
+        spans.push((start..words.len(), TokenKind::Connector));
+        words.push_str(word_below_20(n % 10));
+        spans.push((start + 1..words.len(), TokenKind::Digit));
+    }
+}
+
+const ORD_NUMS_EXCEPTIONS: [(&str, &str); 7] = [
+    ("one", "first"), ("two", "second"), ("three", "third"), ("five", "fifth"),
+    ("eight", "eighth"), ("nine", "ninth"), ("twelve", "twelfth"),
+];
+
+/// Converts a cardinal number word to its **ordinal** form (***twenty-one*** -> ***twenty-first***).
+///
+/// # Arguments
+/// - `cardinal_word`: A string slice (`&str`) that holds a single cardinal number word, such as the
+///   last word of [u128_to_words].
+///
+/// # Returns
+/// A [`String`] containing the ordinal form of the word. For hyphenated words, only the part after
+/// the hyphen is changed.
+///
+/// # Example
+/// ```
+/// use num2en::ordinalize_word;
+///
+/// assert_eq!(ordinalize_word("forty"), "fortieth");
+/// assert_eq!(ordinalize_word("six"), "sixth");
+/// assert_eq!(ordinalize_word("twenty-one"), "twenty-first");
+/// assert_eq!(ordinalize_word("million"), "millionth");
+/// ```
+///
+/// # Notes
+/// - The word is not checked to be a number word; anything else simply gets ***th*** appended
+///   (or ***ieth*** in place of a final ***y***).
+#[allow(clippy::needless_return)]
+pub fn ordinalize_word(cardinal_word: &str) -> String {
+    let (penultimate_word, last_word) = match cardinal_word.find('-') {
+        Some(hyphen_index) => cardinal_word.split_at(hyphen_index + 1),
+        None => ("", cardinal_word),
+    };
+    if let Some(index) = ORD_NUMS_EXCEPTIONS.iter().position(|x| x.0 == last_word) {
+        return penultimate_word.to_string() + ORD_NUMS_EXCEPTIONS[index].1;
+    }
+    else if let Some(stem) = last_word.strip_suffix('y') {
+        return penultimate_word.to_string() + stem + "ieth";
+    }
+    return penultimate_word.to_string() + last_word + "th";
+}
+
+/// Turns the last word of the cardinal `words` into its ordinal (***twenty-one*** -> ***twenty-first***).
+fn ordinalize_last(words: &mut Vec<String>) {
+    if let Some(last_word) = words.pop() {
+        words.push(ordinalize_word(&last_word));
+    }
+}
+
+macro_rules! create_public_conversion_func_of_unsigned_int_ord {
+    ( $t:ty, $name:ident ) => {
+        /// Converts any
+        #[doc = concat!("`", stringify!($t), "`")]
+        /// value to its **ordinal** number representation in words (***first, second, third*** etc.).
+        ///
+        /// # Arguments
+        ///
+        /// - `n`: An unsigned integer
+        #[doc = concat!("(`", stringify!($t), "`)")]
+        /// that represents the number to be converted.
+        ///
+        /// # Returns
+        ///
+        /// A [`String`] containing the English words that represent the input ordinal number.
+        ///
+        #[doc = concat!(
+            "# Example\n\
+            ```\n\
+            use num2en::", stringify!($name), ";\n\n\
+            let number = 12;\n\
+            let words = ", stringify!($name), "(number);\n\
+            assert_eq!(words, \"twelfth\");\n\n\
+            let number = 12_142;\n\
+            let words = ", stringify!($name), "(number);\n\
+            assert_eq!(words, \"twelve thousand one hundred forty-second\");\n\
+            ```"
+        )]
+        ///
+        /// # Notes
+        ///
+        /// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-first").
+        pub fn $name(n: $t) -> String {
+            if n == 0 {
+                return "zeroth".to_string();
+            }
+
+            let mut words = Vec::<String>::new();
+            push_period_words(n as u128, &mut words);
+
+            // Modify the last word to an ordinal word
+            ordinalize_last(&mut words);
+
+            return words.join(" ");
+        }
+    };
+}
+
+create_public_conversion_func_of_unsigned_int_ord!(usize, usize_to_ord_words);
+create_public_conversion_func_of_unsigned_int_ord!(u128, u128_to_ord_words);
+create_public_conversion_func_of_unsigned_int_ord!(u64, u64_to_ord_words);
+create_public_conversion_func_of_unsigned_int_ord!(u32, u32_to_ord_words);
+create_public_conversion_func_of_unsigned_int_ord!(u16, u16_to_ord_words);
+/// Converts any `u8` value to its **ordinal** number representation in words (***first, second, third*** etc.).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u8`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`String`] containing the English words that represent the input ordinal number.
+///
+/// # Examples
+/// ```
+/// use num2en::u8_to_ord_words;
+/// 
+/// let number = 13;
+/// let words = u8_to_ord_words(number);
+/// assert_eq!(words, "thirteenth");
+/// 
+/// let number = 142;
+/// let words = u8_to_ord_words(number);
+/// assert_eq!(words, "one hundred forty-second");
+/// ```
+///
+/// # Notes
+/// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-first").
+pub fn u8_to_ord_words(n: u8) -> String { u16_to_ord_words(n as u16) }
+
+/// Converts any `u128` value to both its **cardinal** and its **ordinal** number representation in words,
+/// spelling the number only once.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+///
+/// # Returns
+/// A tuple of two [`String`]s: the same words as [u128_to_words] and as [u128_to_ord_words].
+///
+/// # Example
+/// ```
+/// use num2en::u128_to_words_both;
+///
+/// let (cardinal, ordinal) = u128_to_words_both(3);
+/// assert_eq!(cardinal, "three");
+/// assert_eq!(ordinal, "third");
+///
+/// let (cardinal, ordinal) = u128_to_words_both(1_021);
+/// assert_eq!(cardinal, "one thousand twenty-one");
+/// assert_eq!(ordinal, "one thousand twenty-first");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn u128_to_words_both(n: u128) -> (String, String) {
+    if n == 0 {
+        return ("zero".to_string(), "zeroth".to_string());
+    }
+
+    let mut words = Vec::<String>::new();
+    push_period_words(n, &mut words);
+
+    let cardinal = words.join(" ");
+    ordinalize_last(&mut words);
+    return (cardinal, words.join(" "));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents how the ordinal is written by [the_nth_noun].
+pub enum OrdinalStyle {
+    /// The ordinal is spelled out (***the third chapter***).
+    Words,
+    /// The ordinal is written with digits and a suffix (***the 3rd chapter***).
+    Suffix,
+}
+
+/// Converts any `u128` value to its **ordinal** number written with digits and a suffix (***1st, 22nd, 113th***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`String`] containing the digits of `n` followed by ***th*** if `n` ends in 11, 12 or 13,
+/// otherwise by ***st***, ***nd*** or ***rd*** if it ends in 1, 2 or 3, and by ***th*** in all other cases.
+///
+/// # Example
+/// ```
+/// use num2en::u128_to_ord_suffix;
+///
+/// assert_eq!(u128_to_ord_suffix(1), "1st");
+/// assert_eq!(u128_to_ord_suffix(22), "22nd");
+/// assert_eq!(u128_to_ord_suffix(113), "113th");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn u128_to_ord_suffix(n: u128) -> String {
+    return n.to_string() + ord_suffix(n);
+}
+
+/// Returns the English ordinal suffix of `n` (***st***, ***nd***, ***rd*** or ***th***).
+#[allow(clippy::needless_return)]
+fn ord_suffix(n: u128) -> &'static str {
+    if (11..=13).contains(&(n % 100)) {
+        return "th";
+    }
+    match n % 10 {
+        1 => return "st",
+        2 => return "nd",
+        3 => return "rd",
+        _ => return "th",
+    }
+}
+
+/// Combines the definite article, an ordinal and a singular noun (***the third chapter***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the ordinal number.
+/// - `noun`: The singular noun that follows the ordinal.
+/// - `style`: Whether the ordinal is spelled out or written as digits with a suffix.
+///
+/// # Returns
+/// A [`String`] containing ***the***, the ordinal and the noun.
+///
+/// # Examples
+/// ```
+/// use num2en::{the_nth_noun, OrdinalStyle};
+///
+/// assert_eq!(the_nth_noun(3, "chapter", OrdinalStyle::Words), "the third chapter");
+/// assert_eq!(the_nth_noun(3, "chapter", OrdinalStyle::Suffix), "the 3rd chapter");
+/// assert_eq!(the_nth_noun(112, "floor", OrdinalStyle::Suffix), "the 112th floor");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn the_nth_noun(n: u128, noun: &str, style: OrdinalStyle) -> String {
+    let ordinal = match style {
+        OrdinalStyle::Words => u128_to_ord_words(n),
+        OrdinalStyle::Suffix => u128_to_ord_suffix(n),
+    };
+
+    return format!("the {} {}", ordinal, noun);
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents where the sign word (***negative***) is placed when spelling negative numbers
+/// (see [WordsOptions::sign_position]).
+pub enum SignPosition {
+    /// Before the number (***negative forty-two***). This is the default.
+    Prefix,
+    /// After the number (***forty-two negative***).
+    Suffix,
+}
+
+/// Adds the sign word of `options` to `words` at the position of `options`.
+fn push_sign(words: &mut Vec<String>, options: &WordsOptions) {
+    match options.sign_position {
+        SignPosition::Prefix => words.insert(0, options.negative_word.to_string()),
+        SignPosition::Suffix => words.push(options.negative_word.to_string()),
+    }
+}
+
+macro_rules! create_public_conversion_func_of_signed_int {
+    ( $t:tt, $name:ident, $name_with_sign:ident ) => {
+        /// Converts any
+        #[doc = concat!("`", stringify!($t), "`")]
+        /// value to its **cardinal** number representation in words (***one, two, three*** etc.).
+        ///
+        /// # Arguments
+        ///
+        /// - `n`: A signed integer
+        #[doc = concat!("(`", stringify!($t), "`)")]
+        /// that represents the number to be converted.
+        ///
+        /// # Returns
+        ///
+        /// A [`String`] containing the English words that represent the input cardinal number.
+        ///
+        #[doc = concat!(
+            "# Example\n\
+            ```\n\
+            use num2en::", stringify!($name), ";\n\n\
+            let number = 1969;\n\
+            let words = ", stringify!($name), "(number);\n\
+            assert_eq!(words, \"one thousand nine hundred sixty-nine\");\n\n\
+            let number = -2918;\n\
+            let words = ", stringify!($name), "(number);\n\
+            assert_eq!(words, \"negative two thousand nine hundred eighteen\");\n\
+            ```"
+        )]
+        ///
+        /// # Notes
+        ///
+        /// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-one").
+        pub fn $name(n: $t) -> String {
+            $name_with_sign(n, SignPosition::Prefix)
+        }
+
+        /// Converts any
+        #[doc = concat!("`", stringify!($t), "`")]
+        /// value to its **cardinal** number representation in words, placing the sign word
+        /// (***negative***) according to `sign_position`.
+        ///
+        /// # Arguments
+        ///
+        /// - `n`: A signed integer
+        #[doc = concat!("(`", stringify!($t), "`)")]
+        /// that represents the number to be converted.
+        /// - `sign_position`: A [`SignPosition`] that determines where the sign word is placed.
+        ///
+        /// # Returns
+        ///
+        /// A [`String`] containing the English words that represent the input cardinal number.
+        ///
+        #[doc = concat!(
+            "# Example\n\
+            ```\n\
+            use num2en::{", stringify!($name_with_sign), ", SignPosition};\n\n\
+            let words = ", stringify!($name_with_sign), "(-42, SignPosition::Suffix);\n\
+            assert_eq!(words, \"forty-two negative\");\n\n\
+            let words = ", stringify!($name_with_sign), "(-42, SignPosition::Prefix);\n\
+            assert_eq!(words, \"negative forty-two\");\n\
+            ```"
+        )]
+        ///
+        /// # Notes
+        ///
+        /// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-one").
+        /// - This function uses [WordsOptions::sign_position] behind the curtains.
+        pub fn $name_with_sign(n: $t, sign_position: SignPosition) -> String {
+            return WordsOptions::new().sign_position(sign_position).i128_to_words(n as i128);
+        }
+    };
+}
+
+macro_rules! signed_to_unsigned {
+    (i8) => { u8 };
+    (i16) => { u16 };
+    (i32) => { u32 };
+    (i64) => { u64 };
+    (i128) => { u128 };
+    (isize) => { usize };
+}
+
+create_public_conversion_func_of_signed_int!(isize, isize_to_words, isize_to_words_with_sign);
+create_public_conversion_func_of_signed_int!(i128, i128_to_words, i128_to_words_with_sign);
+create_public_conversion_func_of_signed_int!(i64, i64_to_words, i64_to_words_with_sign);
+create_public_conversion_func_of_signed_int!(i32, i32_to_words, i32_to_words_with_sign);
+create_public_conversion_func_of_signed_int!(i16, i16_to_words, i16_to_words_with_sign);
+/// Converts any `u8` value to its **cardinal** number representation in words (***one, two, three*** etc.).
+///
+/// # Arguments
+/// - `n`: A signed integer (`u8`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`String`] containing the English words that represent the input cardinal number.
+///
+/// # Examples
+/// ```
+/// use num2en::i8_to_words;
+///
+/// let number = 120;
+/// let words = i8_to_words(number);
+/// assert_eq!(words, "one hundred twenty");
+///
+/// let number = -111;
+/// let words = i8_to_words(number);
+/// assert_eq!(words, "negative one hundred eleven");
+/// ```
+///
+/// # Notes
+/// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-one").
+pub fn i8_to_words(n: i8) -> String { i8_to_words_with_sign(n, SignPosition::Prefix) }
+/// Converts any `i8` value to its **cardinal** number representation in words, placing the sign word
+/// (***negative***) according to `sign_position`.
+///
+/// # Arguments
+/// - `n`: A signed integer (`i8`) that represents the number to be converted.
+/// - `sign_position`: A [`SignPosition`] that determines where the sign word is placed.
+///
+/// # Returns
+/// A [`String`] containing the English words that represent the input cardinal number.
+///
+/// # Example
+/// ```
+/// use num2en::{i8_to_words_with_sign, SignPosition};
+///
+/// let words = i8_to_words_with_sign(-42, SignPosition::Suffix);
+/// assert_eq!(words, "forty-two negative");
+/// ```
+///
+/// # Notes
+/// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-one").
+/// - This function uses [WordsOptions::sign_position] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn i8_to_words_with_sign(n: i8, sign_position: SignPosition) -> String {
+    return WordsOptions::new().sign_position(sign_position).i8_to_words(n);
+}
+
+
+macro_rules! create_public_conversion_func_of_signed_int_ord {
+    ( $t:tt, $name:ident, $unsigned_name:ident ) => {
+        /// Converts any
+        #[doc = concat!("`", stringify!($t), "`")]
+        /// value to its **ordinal** number representation in words (***first, negative second*** etc.).
+        ///
+        /// # Arguments
+        ///
+        /// - `n`: A signed integer
+        #[doc = concat!("(`", stringify!($t), "`)")]
+        /// that represents the number to be converted.
+        ///
+        /// # Returns
+        ///
+        /// A [`String`] containing the English words that represent the input ordinal number,
+        /// prefixed by ***negative*** for negative numbers.
+        ///
+        #[doc = concat!(
+            "# Example\n\
+            ```\n\
+            use num2en::", stringify!($name), ";\n\n\
+            let words = ", stringify!($name), "(-42);\n\
+            assert_eq!(words, \"negative forty-second\");\n\n\
+            let words = ", stringify!($name), "(0);\n\
+            assert_eq!(words, \"zeroth\");\n\
+            ```"
+        )]
+        ///
+        /// # Notes
+        ///
+        #[doc = concat!("- This function uses [", stringify!($unsigned_name), "] behind the curtains.")]
+        pub fn $name(n: $t) -> String {
+            type UnsignedType = signed_to_unsigned!($t);
+            let mut nonnegative_n = n as UnsignedType;
+            if n < 0 && n > <$t>::MIN {
+                // values in range (iX::MIN, 0) don't map correctly to uX without negating first
+                nonnegative_n = -n as UnsignedType;
+            }
+
+            let words = $unsigned_name(nonnegative_n);
+            if n < 0 {
+                return "negative ".to_string() + &words;
+            }
+            return words;
+        }
+    };
+}
+
+create_public_conversion_func_of_signed_int_ord!(isize, isize_to_ord_words, usize_to_ord_words);
+create_public_conversion_func_of_signed_int_ord!(i128, i128_to_ord_words, u128_to_ord_words);
+create_public_conversion_func_of_signed_int_ord!(i64, i64_to_ord_words, u64_to_ord_words);
+create_public_conversion_func_of_signed_int_ord!(i32, i32_to_ord_words, u32_to_ord_words);
+create_public_conversion_func_of_signed_int_ord!(i16, i16_to_ord_words, u16_to_ord_words);
+create_public_conversion_func_of_signed_int_ord!(i8, i8_to_ord_words, u8_to_ord_words);
+
+/// Conversion of integers to their **cardinal** number representation in words, for use in generic code.
+///
+/// # Example
+/// ```
+/// use num2en::ToWords;
+///
+/// fn label<T: ToWords>(x: T) -> String {
+///     "Item ".to_string() + &x.to_words()
+/// }
+///
+/// assert_eq!(label(42u8), "Item forty-two");
+/// assert_eq!(label(-3i64), "Item negative three");
+/// ```
+///
+/// # Notes
+/// - The implementations use the `X_to_words` function of each type behind the curtains.
+pub trait ToWords {
+    /// Converts the value to its **cardinal** number representation in words (***one, two, three*** etc.).
+    fn to_words(&self) -> String;
+}
+
+/// Conversion of integers to their **ordinal** number representation in words, for use in generic code.
+///
+/// # Example
+/// ```
+/// use num2en::ToOrdWords;
+///
+/// assert_eq!(21u32.to_ord_words(), "twenty-first");
+/// assert_eq!((-2i8).to_ord_words(), "negative second");
+/// ```
+///
+/// # Notes
+/// - The implementations use the `X_to_ord_words` function of each type behind the curtains.
+pub trait ToOrdWords {
+    /// Converts the value to its **ordinal** number representation in words (***first, second, third*** etc.).
+    fn to_ord_words(&self) -> String;
+}
+
+macro_rules! impl_to_words {
+    ( $( $t:ty => $func:ident, $ord_func:ident );* ) => {
+        $(
+            impl ToWords for $t {
+                fn to_words(&self) -> String { $func(*self) }
+            }
+
+            impl ToOrdWords for $t {
+                fn to_ord_words(&self) -> String { $ord_func(*self) }
+            }
+        )*
+    }
+}
+
+impl_to_words!(
+    u8 => u8_to_words, u8_to_ord_words; u16 => u16_to_words, u16_to_ord_words;
+    u32 => u32_to_words, u32_to_ord_words; u64 => u64_to_words, u64_to_ord_words;
+    u128 => u128_to_words, u128_to_ord_words; usize => usize_to_words, usize_to_ord_words;
+    i8 => i8_to_words, i8_to_ord_words; i16 => i16_to_words, i16_to_ord_words;
+    i32 => i32_to_words, i32_to_ord_words; i64 => i64_to_words, i64_to_ord_words;
+    i128 => i128_to_words, i128_to_ord_words; isize => isize_to_words, isize_to_ord_words
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Wrapper that displays an integer as its **cardinal** number representation in words.
+///
+/// # Example
+/// ```
+/// use num2en::Words;
+///
+/// assert_eq!(format!("{}", Words(1234)), "one thousand two hundred thirty-four");
+/// assert_eq!(format!("{} apples", Words(-2i8)), "negative two apples");
+/// assert_eq!(format!("[{:>6}]", Words(5u8)), "[  five]");
+/// ```
+///
+/// # Notes
+/// - Without a width or precision, the words are written to the formatter one by one, without
+///   building the whole [`String`] first.
+pub struct Words<T>(pub T);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Wrapper that displays an integer as its **ordinal** number representation in words.
+///
+/// # Example
+/// ```
+/// use num2en::OrdWords;
+///
+/// assert_eq!(format!("the {} floor", OrdWords(21u8)), "the twenty-first floor");
+/// assert_eq!(format!("[{:-<8}]", OrdWords(3u8)), "[third---]");
+/// ```
+///
+/// # Notes
+/// - This type uses [ToOrdWords] behind the curtains.
+pub struct OrdWords<T>(pub T);
+
+/// Writes the words of `n` to `f`, separated by spaces.
+fn write_words_iter(negative: bool, n: u128, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    if f.width().is_some() || f.precision().is_some() {
+        let mut words = String::new();
+        if negative {
+            words.push_str("negative ");
+        }
+        write_u128_words(n, &mut words);
+        return f.pad(&words);
+    }
+
+    if negative {
+        f.write_str("negative ")?;
+    }
+    for (i, word) in u128_words_iter(n).enumerate() {
+        if i > 0 {
+            f.write_str(" ")?;
+        }
+        f.write_str(&word)?;
+    }
+    Ok(())
+}
+
+macro_rules! impl_display_words {
+    ( unsigned: $( $u:ty ),* ; signed: $( $i:ty ),* ) => {
+        $(
+            impl core::fmt::Display for Words<$u> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    write_words_iter(false, self.0 as u128, f)
+                }
+            }
+        )*
+        $(
+            impl core::fmt::Display for Words<$i> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    write_words_iter(self.0 < 0, (self.0 as i128).unsigned_abs(), f)
+                }
+            }
+        )*
+    }
+}
+
+impl_display_words!(unsigned: u8, u16, u32, u64, u128, usize; signed: i8, i16, i32, i64, i128, isize);
+
+impl<T: ToOrdWords> core::fmt::Display for OrdWords<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad(&self.0.to_ord_words())
+    }
+}
+
+/// Converts an integer to its **cardinal** number representation in words, picking the
+/// `X_to_words` function that matches the type of the argument.
+///
+/// # Examples
+/// ```
+/// use num2en::spell;
+///
+/// assert_eq!(spell!(42), "forty-two");
+/// assert_eq!(spell!(-7i8), "negative seven");
+///
+/// let big: u128 = 1_000_000_000_000;
+/// assert_eq!(spell!(big), "one trillion");
+/// ```
+///
+/// # Notes
+/// - Unsuffixed literals follow Rust's usual inference, so `spell!(42)` uses [i32_to_words].
+/// - Literals too large for `i32` need a suffix or a typed binding (e.g. `spell!(5_000_000_000u64)`).
+#[macro_export]
+macro_rules! spell {
+    ( $n:expr ) => {
+        $crate::ToWords::to_words(&$n)
+    };
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents the vocabulary used by [compare_words].
+pub enum CompareStyle {
+    /// ***is greater than***, ***is less than***, ***is equal to***
+    GreaterThan,
+    /// ***is more than***, ***is less than***, ***is equal to***
+    MoreThan,
+    /// ***>***, ***<***, ***=***
+    Symbol,
+}
+
+/// Narrates how two numbers compare (***seven is greater than three***).
+///
+/// # Arguments
+/// - `a`: The left operand.
+/// - `b`: The right operand.
+/// - `style`: The words (or symbol) used for the relation.
+///
+/// # Returns
+/// A [`String`] containing both operands in words with the relation between them.
+///
+/// # Examples
+/// ```
+/// use num2en::{compare_words, CompareStyle};
+///
+/// assert_eq!(compare_words(7, 3, CompareStyle::GreaterThan), "seven is greater than three");
+/// assert_eq!(compare_words(3, 3, CompareStyle::GreaterThan), "three is equal to three");
+/// assert_eq!(compare_words(-2, 1, CompareStyle::Symbol), "negative two < one");
+/// ```
+///
+/// # Notes
+/// - This function uses [i128_to_words] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn compare_words(a: i128, b: i128, style: CompareStyle) -> String {
+    use core::cmp::Ordering;
+
+    let relation = match (style, a.cmp(&b)) {
+        (CompareStyle::GreaterThan, Ordering::Greater) => "is greater than",
+        (CompareStyle::MoreThan, Ordering::Greater) => "is more than",
+        (CompareStyle::GreaterThan | CompareStyle::MoreThan, Ordering::Less) => "is less than",
+        (CompareStyle::GreaterThan | CompareStyle::MoreThan, Ordering::Equal) => "is equal to",
+        (CompareStyle::Symbol, Ordering::Greater) => ">",
+        (CompareStyle::Symbol, Ordering::Less) => "<",
+        (CompareStyle::Symbol, Ordering::Equal) => "=",
+    };
+
+    return i128_to_words(a) + " " + relation + " " + &i128_to_words(b);
+}
+
+
+/// Converts any `u16` value to its **cardinal** number representation in words, reading four-digit
+/// numbers colloquially in hundreds (***twelve hundred***, ***twenty-five hundred***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u16`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`String`] containing the English words that represent the input cardinal number.
+/// <br> Numbers from 1100 to 9999 with a non-zero hundreds digit are read as the number of hundreds
+/// followed by the rest (***nineteen hundred ninety-nine***). All other numbers, including whole
+/// thousands (***two thousand***) and numbers with a zero hundreds digit (***two thousand five***),
+/// are read the same as by [u16_to_words].
+///
+/// # Examples
+/// ```
+/// use num2en::u16_to_words_hundreds_style;
+///
+/// assert_eq!(u16_to_words_hundreds_style(1200), "twelve hundred");
+/// assert_eq!(u16_to_words_hundreds_style(1999), "nineteen hundred ninety-nine");
+/// assert_eq!(u16_to_words_hundreds_style(2500), "twenty-five hundred");
+/// assert_eq!(u16_to_words_hundreds_style(2005), "two thousand five");
+/// ```
+///
+/// # Notes
+/// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-one").
+#[allow(clippy::needless_return)]
+pub fn u16_to_words_hundreds_style(n: u16) -> String {
+    if !(1100..=9999).contains(&n) || (n / 100) % 10 == 0 {
+        return u16_to_words(n);
+    }
+
+    let mut words = Vec::<String>::new();
+    lt100((n / 100) as u8, &DEFAULT_WORDS_OPTIONS, &mut words);
+    words.push("hundred".to_string());
+    let ones_and_tens = (n % 100) as u8;
+    if ones_and_tens != 0 {
+        lt100(ones_and_tens, &DEFAULT_WORDS_OPTIONS, &mut words);
+    }
+    return words.join(" ");
+}
+
+/// Converts any `u16` value to the way building (house) numbers in addresses are read aloud
+/// (***one oh one***, ***twelve thirty-four***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u16`) that represents the building number.
+///
+/// # Returns
+/// A [`String`] containing the spoken building number:
+/// - Numbers below 100 and whole thousands are read as cardinals (***forty-two***, ***two thousand***).
+/// - Otherwise the last two digits are read as a pair after everything before them
+///   (***twelve thirty-four***); a pair of zeros is read as ***hundred*** (***two hundred***) and a
+///   pair with a zero in the tens position as ***oh*** and the digit (***two oh five***).
+///
+/// # Examples
+/// ```
+/// use num2en::u16_to_building_words;
+///
+/// assert_eq!(u16_to_building_words(101), "one oh one");
+/// assert_eq!(u16_to_building_words(110), "one ten");
+/// assert_eq!(u16_to_building_words(200), "two hundred");
+/// assert_eq!(u16_to_building_words(1001), "ten oh one");
+/// assert_eq!(u16_to_building_words(1234), "twelve thirty-four");
+/// assert_eq!(u16_to_building_words(12345), "one twenty-three forty-five");
+/// ```
+///
+/// # Notes
+/// - ***oh*** is only used for a zero in the tens position followed by a non-zero digit, never for trailing zeros.
+#[allow(clippy::needless_return)]
+pub fn u16_to_building_words(n: u16) -> String {
+    if n < 100 || n % 1000 == 0 {
+        return u16_to_words(n);
+    }
+
+    let leading = n / 100;
+    let mut words = vec![if leading < 100 { u16_to_words(leading) } else { u16_to_building_words(leading) }];
+
+    let last_pair = (n % 100) as u8;
+    match last_pair {
+        0 => words.push("hundred".to_string()),
+        1..=9 => {
+            words.push("oh".to_string());
+            lt100(last_pair, &DEFAULT_WORDS_OPTIONS, &mut words);
+        },
+        _ => lt100(last_pair, &DEFAULT_WORDS_OPTIONS, &mut words),
+    }
+
+    return words.join(" ");
+}
+
+/// Converts a year to the way it is read aloud (***nineteen eighty-four***).
+///
+/// # Arguments
+/// - `year`: An unsigned integer (`u16`) that represents the year.
+///
+/// # Returns
+/// A [`String`] containing the spoken year:
+/// - Four-digit years are read as two pairs of digits (***nineteen eighty-four***, ***twenty ten***);
+///   a pair of zeros is read as ***hundred*** (***nineteen hundred***) and a pair with a zero in the
+///   tens position as ***oh*** and the digit (***twenty oh-nine***).
+/// - Whole millennia (***two thousand***), years below 1000 and years above 9999 are read as cardinals.
+///
+/// # Examples
+/// ```
+/// use num2en::u16_to_year_words;
+///
+/// assert_eq!(u16_to_year_words(1984), "nineteen eighty-four");
+/// assert_eq!(u16_to_year_words(2009), "twenty oh-nine");
+/// assert_eq!(u16_to_year_words(1900), "nineteen hundred");
+/// assert_eq!(u16_to_year_words(2000), "two thousand");
+/// assert_eq!(u16_to_year_words(476), "four hundred seventy-six");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn u16_to_year_words(year: u16) -> String {
+    if !(1000..=9999).contains(&year) || year % 1000 == 0 {
+        return u16_to_words(year);
+    }
+
+    let century = (year / 100) as u8;
+    let rest = (year % 100) as u8;
+    let mut words = vec![u8_to_words(century)];
+    match rest {
+        0 => words.push("hundred".to_string()),
+        1..=9 => words.push("oh-".to_string() + &u8_to_words(rest)),
+        _ => words.push(u8_to_words(rest)),
+    }
+    return words.join(" ");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents how [power_to_words_with] reads a power.
+pub enum PowerStyle {
+    /// The exponent is read as an ordinal (***ten to the negative third***). This is the default.
+    Ordinal,
+    /// A negative exponent is read as ***per*** and the value of the reciprocal (***per thousand***).
+    /// Other powers are read like with [PowerStyle::Ordinal].
+    Per,
+}
+
+/// Converts a power (`base` raised to `exponent`) to words, reading the exponent as an ordinal
+/// (***ten to the third***).
+///
+/// # Arguments
+/// - `base`: A signed integer (`i128`) that represents the base.
+/// - `exponent`: A signed integer (`i128`) that represents the exponent.
+///
+/// # Returns
+/// A [`String`] in the `<base> to the <ordinal exponent>` format.
+/// <br> A negative exponent is read with a ***negative*** prefix (***ten to the negative third***).
+///
+/// # Examples
+/// ```
+/// use num2en::power_to_words;
+///
+/// assert_eq!(power_to_words(10, 3), "ten to the third");
+/// assert_eq!(power_to_words(10, -3), "ten to the negative third");
+/// assert_eq!(power_to_words(2, 0), "two to the zeroth");
+/// assert_eq!(power_to_words(10, 1), "ten");
+/// ```
+///
+/// # Notes
+/// - An exponent of 1 is omitted, so only the base is spelled.
+/// - An exponent of 0 is read as ***to the zeroth*** rather than being evaluated to ***one***.
+/// - This function uses [power_to_words_with] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn power_to_words(base: i128, exponent: i128) -> String {
+    return power_to_words_with(base, exponent, PowerStyle::Ordinal);
+}
+
+/// Same as [power_to_words], except that `style` determines how a negative exponent is read.
+///
+/// # Example
+/// ```
+/// use num2en::{power_to_words_with, PowerStyle};
+///
+/// assert_eq!(power_to_words_with(10, -3, PowerStyle::Per), "per thousand");
+/// assert_eq!(power_to_words_with(2, -3, PowerStyle::Per), "per eight");
+/// assert_eq!(power_to_words_with(10, 3, PowerStyle::Per), "ten to the third");
+/// assert_eq!(power_to_words_with(10, -3, PowerStyle::Ordinal), "ten to the negative third");
+/// ```
+///
+/// # Notes
+/// - This function uses [WordsOptions::power_to_words] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn power_to_words_with(base: i128, exponent: i128, style: PowerStyle) -> String {
+    return DEFAULT_WORDS_OPTIONS.power_to_words(base, exponent, style);
+}
+
+/// Converts any `u128` value to its normalized scientific notation in words
+/// (***one point two three times ten to the sixth***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`String`] containing the mantissa (between one and ten, without trailing zeros) read with
+/// ***point***, followed by ***times*** and the power of ten read by [power_to_words].
+///
+/// # Examples
+/// ```
+/// use num2en::scientific_words;
+///
+/// assert_eq!(scientific_words(1_230_000), "one point two three times ten to the sixth");
+/// assert_eq!(scientific_words(5_000), "five times ten to the third");
+/// assert_eq!(scientific_words(42), "four point two times ten");
+/// assert_eq!(scientific_words(7), "seven times ten to the zeroth");
+/// assert_eq!(scientific_words(0), "zero");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn scientific_words(n: u128) -> String {
+    if n == 0 {
+        return "zero".to_string();
+    }
+
+    let digits = n.to_string();
+    let significant_digits = digits.trim_end_matches('0');
+
+    let mut words = vec![u8_to_words(significant_digits.as_bytes()[0] - b'0')];
+    if significant_digits.len() > 1 {
+        words.push("point".to_string());
+        words.push(str_digits_to_words(&significant_digits[1..]).unwrap());
+    }
+    words.push("times".to_string());
+    words.push(power_to_words(10, digits.len() as i128 - 1));
+
+    return words.join(" ");
+}
+
+/// Converts any `u128` value to its expanded form, a sum of its place values in words
+/// (***three thousand plus four hundred plus zero tens plus five ones***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+/// - `include_zeros`: Whether places holding a zero digit are included (e.g. ***zero tens***).
+///
+/// # Returns
+/// A [`String`] with one term per place, from the most significant one, joined by ***plus***.
+/// <br> The ones and tens are read as counts of ***ones*** and ***tens***, larger places by their value.
+///
+/// # Examples
+/// ```
+/// use num2en::expanded_form_words;
+///
+/// let words = expanded_form_words(3405, true);
+/// assert_eq!(words, "three thousand plus four hundred plus zero tens plus five ones");
+///
+/// let words = expanded_form_words(3405, false);
+/// assert_eq!(words, "three thousand plus four hundred plus five ones");
+/// ```
+///
+/// # Notes
+/// - Zero is read as ***zero***.
+#[allow(clippy::needless_return)]
+pub fn expanded_form_words(n: u128, include_zeros: bool) -> String {
+    if n == 0 {
+        return "zero".to_string();
+    }
+
+    let digits = n.to_string();
+    let mut terms = Vec::<String>::new();
+    for (i, byte) in digits.bytes().enumerate() {
+        let digit = (byte - b'0') as u128;
+        let place = digits.len() - 1 - i;
+        if digit == 0 && !include_zeros {
+            continue;
+        }
+        terms.push(match place {
+            0 => count_to_words(digit, "one", "ones"),
+            1 => count_to_words(digit, "ten", "tens"),
+            _ if digit == 0 => {
+                let place_name = u128_to_words(10u128.pow(place as u32));
+                "zero ".to_string() + place_name.strip_prefix("one ").unwrap_or(&place_name) + "s"
+            },
+            _ => u128_to_words(digit * 10u128.pow(place as u32)),
+        });
+    }
+    return terms.join(" plus ");
+}
+
+/// Converts `n` to the given radix and spells each of the resulting digits, followed by the name of
+/// the base (***one one zero one in binary***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+/// - `radix`: The base to convert `n` to, between 2 and 36.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(String)`: The digits (***zero*** to ***nine***, then the letters ***a*** to ***z*** for 10 to 35),
+///   followed by ***in binary***, ***in octal***, ***in decimal***, ***in hexadecimal***, or ***in base ...***
+///   for other radixes.
+/// - `Err(RadixError)`: [RadixError::Unsupported] if `radix` is not between 2 and 36.
+///
+/// # Examples
+/// ```
+/// use num2en::base_narration;
+/// # use num2en::RadixError;
+///
+/// assert_eq!(base_narration(13, 2), Ok("one one zero one in binary".to_string()));
+/// assert_eq!(base_narration(255, 16), Ok("f f in hexadecimal".to_string()));
+/// assert_eq!(base_narration(100, 5), Ok("four zero zero in base five".to_string()));
+/// assert_eq!(base_narration(100, 37), Err(RadixError::Unsupported));
+/// ```
+#[allow(clippy::needless_return)]
+pub fn base_narration(n: u128, radix: u32) -> Result<String, RadixError> {
+    if !(2..=36).contains(&radix) {
+        return Err(RadixError::Unsupported);
+    }
+
+    let mut digits = Vec::<String>::new();
+    let mut rest = n;
+    loop {
+        let digit = (rest % radix as u128) as u32;
+        digits.push(match digit {
+            0..=9 => u8_to_words(digit as u8),
+            _ => core::char::from_digit(digit, radix).unwrap().to_string(),
+        });
+        rest /= radix as u128;
+        if rest == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+
+    let base_name = match radix {
+        2 => "binary".to_string(),
+        8 => "octal".to_string(),
+        10 => "decimal".to_string(),
+        16 => "hexadecimal".to_string(),
+        _ => "base ".to_string() + &u32_to_words(radix),
+    };
+
+    return Ok(digits.join(" ") + " in " + &base_name);
+}
+
+#[derive(Debug, PartialEq)]
+/// Represents the possible error that can occur when calling [base_narration].
+pub enum RadixError {
+    /// Indicates that the radix is not between 2 and 36.
+    Unsupported,
+}
+
+impl RadixError {
+    /// Returns a short human-readable description of the error.
+    pub fn message(&self) -> &'static str {
+        match self {
+            RadixError::Unsupported => "radix must be between 2 and 36",
+        }
+    }
+}
+
+impl core::fmt::Display for RadixError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RadixError {}
+
+
+#[derive(Debug, PartialEq)]
+/// Represents the possible errors that can occur when calling [str_digits_to_words].
+pub enum DigitConversionError {
+    /// Indicates that the string contains a character other than `0`, `1`, `2`, `3`, `4`, `5`, `6`, `7`, `8`, or `9`.
+    InvalidCharacter,
+}
+
+impl DigitConversionError {
+    /// Returns a short human-readable description of the error.
+    pub fn message(&self) -> &'static str {
+        match self {
+            DigitConversionError::InvalidCharacter => "string contains a character that is not a digit",
+        }
+    }
+}
+
+impl core::fmt::Display for DigitConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DigitConversionError {}
+
+/// Converts any string of digits (`0`-`9`) to a string of all the digits spelled out individually.
+///
+/// # Arguments
+/// - `digits`: `&str` of digits to be converted.
+///
+/// # Returns
+/// [`Result`]`<`[`String`]`, `[`DigitConversionError`]`>`
+/// 
+/// The string contains all the digits spelled out individually.
+/// 
+/// For example, `"123"` becomes `"one two three"`.
+///
+/// # Examples
+/// ```
+/// use num2en::str_digits_to_words;
+/// # use num2en::DigitConversionError;
+/// 
+/// let digits = "12408842";
+/// let result = str_digits_to_words(digits);
+/// assert_eq!(result, Ok("one two four zero eight eight four two".to_string()));
+/// 
+/// let digits = "00015000";
+/// let result = str_digits_to_words(digits);
+/// assert_eq!(result, Ok("zero zero zero one five zero zero zero".to_string()));
+/// 
+/// // A string with non-digit characters results in an error.
+/// let invalid_string = "124brb";
+/// let result = str_digits_to_words(invalid_string);
+/// assert_eq!(result, Err(DigitConversionError::InvalidCharacter));
+/// 
+/// // An empty string doesn't do anything.
+/// let empty_string = "";
+/// let result = str_digits_to_words(empty_string);
+/// assert_eq!(result, Ok("".to_string()));
+/// ```
+pub fn str_digits_to_words(digits: &str) -> Result<String, DigitConversionError> {
+    let mut words = Vec::with_capacity(digits.len());
+    for digit in digits.chars() {
+        words.push(match digit {
+            '0' => "zero",
+            '1' => "one",
+            '2' => "two",
+            '3' => "three",
+            '4' => "four",
+            '5' => "five",
+            '6' => "six",
+            '7' => "seven",
+            '8' => "eight",
+            '9' => "nine",
+            _ => return Err(DigitConversionError::InvalidCharacter)
+        });
+    }
+    Ok(words.join(" "))
+}
+
+/// Converts any `u128` value to words, spelling each of its decimal digits individually
+/// (***one two three four***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`String`] containing the digits of `n` spelled out one by one, without any leading zeros.
+///
+/// # Example
+/// ```
+/// use num2en::u128_to_digit_words;
+///
+/// assert_eq!(u128_to_digit_words(1234), "one two three four");
+/// assert_eq!(u128_to_digit_words(1_000), "one zero zero zero");
+/// assert_eq!(u128_to_digit_words(0), "zero");
+/// ```
+///
+/// # Notes
+/// - This function uses [str_digits_to_words] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn u128_to_digit_words(n: u128) -> String {
+    return str_digits_to_words(&n.to_string()).unwrap();
+}
+
+/// Same as [str_digits_to_words], except that `0` is read as ***oh***, like in phone numbers and codes.
+///
+/// # Example
+/// ```
+/// use num2en::{str_digits_to_words_phone, DigitConversionError};
+///
+/// assert_eq!(str_digits_to_words_phone("0800"), Ok("oh eight oh oh".to_string()));
+/// assert_eq!(str_digits_to_words_phone("08-00"), Err(DigitConversionError::InvalidCharacter));
+/// ```
+///
+/// # Notes
+/// - This function uses [str_digits_to_words] and [override_words] behind the curtains.
+pub fn str_digits_to_words_phone(digits: &str) -> Result<String, DigitConversionError> {
+    str_digits_to_words(digits).map(|words| override_words(&words, &[("zero", "oh")]))
+}
+
+/// Same as [str_digits_to_words_phone], except that runs of two or three identical digits are read
+/// as ***double*** and ***triple*** (***double seven double oh***), like British phone numbers.
+///
+/// # Example
+/// ```
+/// use num2en::str_digits_to_words_phone_compressed;
+///
+/// assert_eq!(str_digits_to_words_phone_compressed("7700"), Ok("double seven double oh".to_string()));
+/// assert_eq!(str_digits_to_words_phone_compressed("0001"), Ok("triple oh one".to_string()));
+/// assert_eq!(str_digits_to_words_phone_compressed("5555"), Ok("five five five five".to_string()));
+/// ```
+///
+/// # Notes
+/// - Runs of four or more identical digits are not compressed, but spelled individually.
+pub fn str_digits_to_words_phone_compressed(digits: &str) -> Result<String, DigitConversionError> {
+    let mut words = Vec::<&str>::new();
+    let bytes = digits.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return Err(DigitConversionError::InvalidCharacter);
+        }
+        let digit = bytes[i];
+        let run_length = bytes[i..].iter().take_while(|&&byte| byte == digit).count();
+        let digit_word = match digit {
+            b'0' => "oh",
+            _ => NUMS_SMALLER_THAN_20[(digit - b'1') as usize],
+        };
+        match run_length {
+            2 => words.extend(["double", digit_word]),
+            3 => words.extend(["triple", digit_word]),
+            _ => words.extend(core::iter::repeat(digit_word).take(run_length)),
+        }
+        i += run_length;
+    }
+    Ok(words.join(" "))
+}
+
+/// Converts any `u32` value to its digits spelled out individually, padded with leading zeros to
+/// a fixed number of digits (***zero zero seven***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u32`) that represents the number to be converted.
+/// - `width`: The number of digits to be spelled.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(String)`: The `width` digits spelled out individually.
+/// - `Err(PaddedDigitConversionError)`: [PaddedDigitConversionError::TooManyDigits] if `n` does not fit
+///   in `width` digits, or [PaddedDigitConversionError::WidthTooLarge] if `width` is greater than
+///   [MAX_PADDED_DIGIT_WIDTH].
+///
+/// # Example
+/// ```
+/// use num2en::u32_to_padded_digit_words;
+/// # use num2en::PaddedDigitConversionError;
+///
+/// assert_eq!(u32_to_padded_digit_words(7, 3), Ok("zero zero seven".to_string()));
+/// assert_eq!(u32_to_padded_digit_words(1234, 3), Err(PaddedDigitConversionError::TooManyDigits));
+/// assert_eq!(u32_to_padded_digit_words(7, usize::MAX), Err(PaddedDigitConversionError::WidthTooLarge));
+/// ```
+///
+/// # Notes
+/// - This function uses [str_digits_to_words] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn u32_to_padded_digit_words(n: u32, width: usize) -> Result<String, PaddedDigitConversionError> {
+    if width > MAX_PADDED_DIGIT_WIDTH {
+        return Err(PaddedDigitConversionError::WidthTooLarge);
+    }
+    let digits = n.to_string();
+    if digits.len() > width {
+        return Err(PaddedDigitConversionError::TooManyDigits);
+    }
+    let padded = "0".repeat(width - digits.len()) + &digits;
+    return Ok(str_digits_to_words(&padded).unwrap());
+}
+
+/// The largest `width` accepted by [u32_to_padded_digit_words].
+pub const MAX_PADDED_DIGIT_WIDTH: usize = u16::MAX as usize;
+
+#[derive(Debug, PartialEq)]
+/// Represents the possible errors that can occur when calling [u32_to_padded_digit_words].
+pub enum PaddedDigitConversionError {
+    /// Indicates that the number has more digits than the requested width.
+    TooManyDigits,
+    /// Indicates that the requested width is greater than [MAX_PADDED_DIGIT_WIDTH].
+    WidthTooLarge,
+}
+
+impl PaddedDigitConversionError {
+    /// Returns a short human-readable description of the error.
+    pub fn message(&self) -> &'static str {
+        match self {
+            PaddedDigitConversionError::TooManyDigits => "number has more digits than the requested width",
+            PaddedDigitConversionError::WidthTooLarge => "requested width is too large",
+        }
+    }
+}
+
+impl core::fmt::Display for PaddedDigitConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PaddedDigitConversionError {}
+
+
+#[derive(Debug, PartialEq)]
+/// Represents the possible errors that can occur when calling [str_to_words].
+pub enum StrConversionError {
+    /// This could mean the string contains invalid characters or is in an incorrect format.
+    InvalidString,
+    /// Indicates that the value is too large to be converted.
+    TooLarge,
+}
+
+impl StrConversionError {
+    /// Returns a short human-readable description of the error.
+    ///
+    /// # Example
+    /// ```
+    /// use num2en::StrConversionError;
+    ///
+    /// assert_eq!(StrConversionError::TooLarge.message(), "number is too large to convert (max is 2^128 - 1)");
+    /// assert_eq!(format!("{}", StrConversionError::InvalidString), "input string is not a valid number");
+    /// ```
+    pub fn message(&self) -> &'static str {
+        match self {
+            StrConversionError::InvalidString => "input string is not a valid number",
+            StrConversionError::TooLarge => "number is too large to convert (max is 2^128 - 1)",
+        }
+    }
+}
+
+impl core::fmt::Display for StrConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StrConversionError {}
+
+/// Converts any* string of a (decimal) number to a number representation in words.
+///
+/// # Arguments
+/// - `string`: `&str` representing a number in the `... xxxxxx.xxxxxx ...` format, where `x` is any digit.
+///   <br> * The integer part must be 2<sup>128</sup> - 1 (~ 340 undecillion) or smaller, while
+///   the decimal part is unrestricted.
+///
+/// # Returns
+/// [`Result`]`<`[`String`]`, `[`StrConversionError`]`>`
+/// 
+/// The string contains the English words that represent the input number.
+/// 
+/// For example, `"123.456"` becomes `"one hundred twenty-three point four five six"`.
+///
+/// # Examples
+/// ```
+/// use num2en::str_to_words;
+/// # use num2en::StrConversionError;
+/// 
+/// let number = "123.123";
+/// let result = str_to_words(number);
+/// assert_eq!(result, Ok("one hundred twenty-three point one two three".to_string()));
+/// 
+/// let number = "1095";
+/// let result = str_to_words(number);
+/// assert_eq!(result, Ok("one thousand ninety-five".to_string()));
+/// 
+/// let number = "0.0042";
+/// let result = str_to_words(number);
+/// assert_eq!(result, Ok("zero point zero zero four two".to_string()));
+///
+/// let number = ".0042";
+/// let result = str_to_words(number);
+/// assert_eq!(result, Ok("point zero zero four two".to_string()));
+/// 
+/// let number = "1095.";
+/// let result = str_to_words(number);
+/// assert_eq!(result, Ok("one thousand ninety-five point".to_string()));
+/// 
+/// // Leading zeros are ignored.
+/// let number = "0003000";
+/// let result = str_to_words(number);
+/// assert_eq!(result, Ok("three thousand".to_string()));
+/// 
+/// // This is (almost) the largest allowed number (it could have any number of nines):
+/// let number = "340282366920938463463374607431768211455.99999999";
+/// let result = str_to_words(number);
+/// assert_eq!(result, Ok("three hundred forty undecillion two hundred eighty-two \
+/// decillion three hundred sixty-six nonillion nine hundred twenty octillion nine \
+/// hundred thirty-eight septillion four hundred sixty-three sextillion four hundred \
+/// sixty-three quintillion three hundred seventy-four quadrillion six hundred seven \
+/// trillion four hundred thirty-one billion seven hundred sixty-eight million two \
+/// hundred eleven thousand four hundred fifty-five point nine nine nine nine nine \
+/// nine nine nine".to_string()));
+/// 
+/// // A string with invalid characters results in an error.
+/// let invalid_string = "235:53";
+/// let result = str_to_words(invalid_string);
+/// assert_eq!(result, Err(StrConversionError::InvalidString));
+/// 
+/// // An empty string doesn't do anything.
+/// let empty_string = "";
+/// let result = str_to_words(empty_string);
+/// assert_eq!(result, Ok("".to_string()));
+/// ```
+/// 
+/// # Notes
+/// - Scientific notation (e.g. `"4.2e1"`, `"1.5E-3"`) is expanded to a plain decimal number first,
+///   keeping all the digits of the mantissa (`"4.20e1"` is read like `"42.0"`). The exponent must
+///   be between -32768 and 32767, otherwise a [TooLarge](StrConversionError::TooLarge) error is returned.
+/// - The integer part may be grouped with commas or underscores (`"1,234,567"`, `"1_000_000"`). Grouping is
+///   validated, not merely stripped: groups after the first one must have exactly three digits, and only one
+///   kind of separator may be used. Separators in the fractional part are rejected.
+/// - A leading `+` is accepted and ignored. Negative zero (`"-0"`, `"-0.00"`) is read without the
+///   ***negative*** prefix, like plain zero.
+/// - This function supports only numbers between `-u128::MAX-1` (exclusive) and `u128::MAX+1` (exclusive).
+/// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-one").
+/// - This function uses [u128_to_words] and [str_digits_to_words] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn str_to_words(string: &str) -> Result<String, StrConversionError> {
+    let (integer_words, point_word, fractional_words) = str_to_words_parts(string)?;
+
+    let words = [integer_words, point_word, fractional_words];
+    return Ok(words.iter().filter(|x| !x.is_empty()).cloned().collect::<Vec<_>>().join(" "));
+}
+
+/// Converts any* string of a (decimal) number to a number representation in words, split into
+/// the integer part, the decimal point and the fractional part.
+///
+/// # Arguments
+/// - `string`: `&str` representing a number in the `... xxxxxx.xxxxxx ...` format, where `x` is any digit.
+///   <br> * The same restrictions as in [str_to_words] apply.
+///
+/// # Returns
+/// [`Result`]`<(`[`String`]`, `[`String`]`, `[`String`]`), `[`StrConversionError`]`>`
+///
+/// The tuple contains the integer words (including the ***negative*** prefix), the word for the
+/// decimal point and the fractional words, in that order. Any part not present in the input is an
+/// empty string.
+///
+/// # Examples
+/// ```
+/// use num2en::str_to_words_parts;
+///
+/// let parts = str_to_words_parts("-12.05").unwrap();
+/// assert_eq!(parts, ("negative twelve".to_string(), "point".to_string(), "zero five".to_string()));
+///
+/// let parts = str_to_words_parts("12").unwrap();
+/// assert_eq!(parts, ("twelve".to_string(), "".to_string(), "".to_string()));
+///
+/// let parts = str_to_words_parts(".5").unwrap();
+/// assert_eq!(parts, ("".to_string(), "point".to_string(), "five".to_string()));
+/// ```
+///
+/// # Notes
+/// - Joining the non-empty parts with spaces gives the same result as [str_to_words].
+#[allow(clippy::needless_return)]
+pub fn str_to_words_parts(string: &str) -> Result<(String, String, String), StrConversionError> {
+    let number = parse_number_str(string)?;
+    let (mut integer_words, point_word, fractional_words) = number_str_to_words_parts(&number);
+
+    if number.negative {
+        integer_words = if integer_words.is_empty() {
+            "negative".to_string()
+        } else {
+            "negative ".to_string() + &integer_words
+        };
+    }
+
+    return Ok((integer_words, point_word, fractional_words));
+}
+
+/// Same as [str_to_words], except that the sign word (***negative***) is placed according to `sign_position`.
+///
+/// # Example
+/// ```
+/// use num2en::{str_to_words_with_sign, SignPosition};
+///
+/// let words = str_to_words_with_sign("-42.5", SignPosition::Suffix);
+/// assert_eq!(words, Ok("forty-two point five negative".to_string()));
+///
+/// let words = str_to_words_with_sign("-1000000", SignPosition::Suffix);
+/// assert_eq!(words, Ok("one million negative".to_string()));
+/// ```
+///
+/// # Notes
+/// - This function uses [WordsOptions::sign_position] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn str_to_words_with_sign(string: &str, sign_position: SignPosition) -> Result<String, StrConversionError> {
+    return WordsOptions::new().sign_position(sign_position).str_to_words(string);
+}
+
+/// Same as [str_to_words], except that the string is read from `reader` and the words are
+/// appended to `out`, spelling the fractional digits chunk by chunk as they are read.
+///
+/// # Arguments
+/// - `reader`: Any [`Read`](std::io::Read) source of a number string, in the format accepted by [str_to_words].
+/// - `out`: The [`String`] the words are appended to.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(())`: The words have been appended to `out`.
+/// - `Err(StrConversionError)`: The same errors as [str_to_words]. Reading errors and invalid UTF-8
+///   are reported as [StrConversionError::InvalidString]. On error, `out` is left as it was.
+///
+/// # Example
+/// ```
+/// use num2en::read_to_words;
+///
+/// let mut words = String::new();
+/// read_to_words("-3.1415".as_bytes(), &mut words).unwrap();
+/// assert_eq!(words, "negative three point one four one five");
+/// ```
+///
+/// # Notes
+/// - Only the part before the decimal point is buffered; the fractional part may be arbitrarily long.
+/// - Unlike [str_to_words], scientific notation is not supported, since the exponent would only be known
+///   after the fractional digits have already been written.
+/// - This function is only available with the `std` feature (enabled by default).
+#[cfg(feature = "std")]
+#[allow(clippy::needless_return)]
+pub fn read_to_words<R: std::io::Read>(mut reader: R, out: &mut String) -> Result<(), StrConversionError> {
+    let original_len = out.len();
+    let result = read_to_words_inner(&mut reader, out);
+    if result.is_err() {
+        out.truncate(original_len);
+    }
+    return result;
+}
+
+#[cfg(feature = "std")]
+fn read_to_words_inner<R: std::io::Read>(reader: &mut R, out: &mut String) -> Result<(), StrConversionError> {
+    let mut buffer = [0u8; 4096];
+    let mut integer_part = Vec::<u8>::new();
+    let mut fractional_start = None;
+
+    // Collect everything up to the decimal point
+    while fractional_start.is_none() {
+        let len = reader.read(&mut buffer).map_err(|_| StrConversionError::InvalidString)?;
+        if len == 0 {
+            break;
+        }
+        match buffer[..len].iter().position(|&byte| byte == b'.') {
+            Some(i) => {
+                integer_part.extend_from_slice(&buffer[..i]);
+                fractional_start = Some((i + 1, len));
+            },
+            None => integer_part.extend_from_slice(&buffer[..len]),
+        }
+    }
+    let integer_part = String::from_utf8(integer_part).map_err(|_| StrConversionError::InvalidString)?;
+    if integer_part.contains(|c| c == 'e' || c == 'E') {
+        return Err(StrConversionError::InvalidString);
+    }
+
+    let (start, len) = match fractional_start {
+        Some(range) => range,
+        None => {
+            out.push_str(&str_to_words(&integer_part)?);
+            return Ok(());
+        },
+    };
+
+    // Validate and spell the integer part together with a placeholder fractional digit
+    let mut at_least_one_digit_flag = integer_part.bytes().any(|byte| byte.is_ascii_digit());
+    let negative = integer_part.starts_with('-');
+    let (integer_words, point_word, _) = str_to_words_parts(&(integer_part + ".0"))?;
+    // the sign of a zero integer part is only written once a non-zero fractional digit shows up
+    let sign_index = out.len();
+    let mut sign_pending = negative && !integer_words.starts_with("negative");
+    if !integer_words.is_empty() {
+        out.push_str(&integer_words);
+        out.push(' ');
+    }
+    out.push_str(&point_word);
+
+    let mut chunk = &buffer[start..len];
+    loop {
+        if !chunk.is_empty() {
+            // Non-digit bytes (including a second '.') are rejected by str_digits_to_words
+            let digits = core::str::from_utf8(chunk).map_err(|_| StrConversionError::InvalidString)?;
+            let words = str_digits_to_words(digits).map_err(|_| StrConversionError::InvalidString)?;
+            out.push(' ');
+            out.push_str(&words);
+            at_least_one_digit_flag = true;
+            if sign_pending && digits.bytes().any(|byte| byte != b'0') {
+                out.insert_str(sign_index, "negative ");
+                sign_pending = false;
+            }
+        }
+        let len = reader.read(&mut buffer).map_err(|_| StrConversionError::InvalidString)?;
+        if len == 0 {
+            if !at_least_one_digit_flag {
+                return Err(StrConversionError::InvalidString);
+            }
+            return Ok(());
+        }
+        chunk = &buffer[..len];
+    }
+}
+
+/// Spells the integer part, the decimal point and the fractional part of `number`, ignoring its sign.
+#[allow(clippy::needless_return)]
+fn number_str_to_words_parts(number: &NumberStr) -> (String, String, String) {
+    let mut integer_words = String::new();
+    if let Some(integer_part) = number.integer_part {
+        integer_words = u128_to_words(integer_part);
+    }
+
+    let mut point_word = String::new();
+    let mut fractional_words = String::new();
+    if let Some(fractional_part) = &number.fractional_part {
+        point_word.push_str("point");
+        fractional_words = str_digits_to_words(fractional_part).unwrap();
+    }
+
+    return (integer_words, point_word, fractional_words);
+}
+
+/// A number string split by [parse_number_str].
+struct NumberStr<'a> {
+    negative: bool,
+    /// `None` if the string has no digits before the decimal point
+    integer_part: Option<u128>,
+    /// `None` if the string has no decimal point, otherwise the (possibly empty) digits after it
+    fractional_part: Option<alloc::borrow::Cow<'a, str>>,
+}
+
+#[allow(clippy::needless_return)]
+fn parse_number_str(string: &str) -> Result<NumberStr<'_>, StrConversionError> {
+    if !string.contains(|c| c == 'e' || c == 'E' || c == ',' || c == '_') {
+        return parse_plain_number_str(string);
+    }
+
+    let mut expanded = strip_digit_grouping(string)?;
+    if expanded.contains(|c| c == 'e' || c == 'E') {
+        expanded = expand_scientific_notation(&expanded)?;
+    }
+    let number = parse_plain_number_str(&expanded)?;
+    return Ok(NumberStr {
+        negative: number.negative,
+        integer_part: number.integer_part,
+        fractional_part: number.fractional_part.map(|fractional_part| fractional_part.into_owned().into()),
+    });
+}
+
+/// Removes the grouping separators (`','` or `'_'`) from the integer part of a number string.
+///
+/// Grouping is validated: a single kind of separator has to split the integer digits into groups of
+/// exactly three, except for the first group, which may be one to three digits long.
+#[allow(clippy::needless_return)]
+fn strip_digit_grouping(string: &str) -> Result<String, StrConversionError> {
+    let integer_end = string.find(|c| c == '.' || c == 'e' || c == 'E').unwrap_or(string.len());
+    let (integer_part, rest) = string.split_at(integer_end);
+    let (negative, integer_part) = split_sign(integer_part);
+    let sign = if negative { "-" } else { "" };
+
+    let separator = match integer_part.chars().find(|&c| c == ',' || c == '_') {
+        Some(separator) => separator,
+        None => return Ok(string.to_string()),
+    };
+
+    let mut stripped = sign.to_string();
+    for (i, group) in integer_part.split(separator).enumerate() {
+        let valid_length = if i == 0 { (1..=3).contains(&group.len()) } else { group.len() == 3 };
+        if !valid_length || !group.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(StrConversionError::InvalidString);
+        }
+        stripped.push_str(group);
+    }
+    stripped.push_str(rest);
+
+    return Ok(stripped);
+}
+
+/// Rewrites a number in scientific notation (`"-1.5e-3"`) as a plain decimal number (`"-0.0015"`).
+#[allow(clippy::needless_return)]
+fn expand_scientific_notation(string: &str) -> Result<String, StrConversionError> {
+    let exponent_index = string.find(|c| c == 'e' || c == 'E').unwrap();
+    let (mantissa, exponent) = (&string[..exponent_index], &string[exponent_index + 1..]);
+
+    let exponent_digits = exponent.strip_prefix(|c| c == '+' || c == '-').unwrap_or(exponent);
+    if exponent_digits.is_empty() || !exponent_digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(StrConversionError::InvalidString);
+    }
+    // limits the length of the expanded string
+    let exponent = exponent.parse::<i16>().map_err(|_| StrConversionError::TooLarge)? as isize;
+
+    let (negative, mantissa) = split_sign(mantissa);
+    let sign = if negative { "-" } else { "" };
+    let (integer_digits, fractional_digits) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = integer_digits.to_string() + fractional_digits;
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(StrConversionError::InvalidString);
+    }
+
+    let point_index = integer_digits.len() as isize + exponent;
+    if point_index <= 0 {
+        return Ok(format!("{}0.{}{}", sign, "0".repeat(-point_index as usize), digits));
+    }
+    if point_index as usize >= digits.len() {
+        return Ok(format!("{}{}{}", sign, digits, "0".repeat(point_index as usize - digits.len())));
+    }
+    let (integer_digits, fractional_digits) = digits.split_at(point_index as usize);
+    return Ok(format!("{}{}.{}", sign, integer_digits, fractional_digits));
+}
+
+#[allow(clippy::needless_return)]
+fn parse_plain_number_str(string: &str) -> Result<NumberStr<'_>, StrConversionError> {
+    use core::num::IntErrorKind;
+
+    let mut number = NumberStr { negative: false, integer_part: None, fractional_part: None };
+
+    if string.is_empty() {
+        return Ok(number);
+    }
+
+    // Validity check
+    let mut decimal_point_flag = false;
+    let mut at_least_one_digit_flag = false;
+    for (i, byte) in string.bytes().enumerate() {
+        if byte == b'.' {
+            if decimal_point_flag {
+                return Err(StrConversionError::InvalidString);
+            }
+            decimal_point_flag = true;
+            continue;
+        }
+        if byte.is_ascii_digit() {
+            at_least_one_digit_flag = true;
+        }
+        else if !(i == 0 && (byte == b'-' || byte == b'+')) {
+            return Err(StrConversionError::InvalidString);
+        }
+    }
+    if !at_least_one_digit_flag {
+        return Err(StrConversionError::InvalidString)
+    }
+
+    let (negative, string) = split_sign(string);
+    number.negative = negative;
+
+    let floating_point_index_option = string.find('.');
+
+    let integer_part_result = string[..floating_point_index_option.unwrap_or(string.len())].parse::<u128>();
+
+    match integer_part_result {
+        Err(parse_int_err) => {
+            match parse_int_err.kind() {
+                // no digits before the decimal point (".5", "-.5"); the check above guarantees a
+                // digit after it, so a lone "-" or "-." never gets here
+                IntErrorKind::Empty => {},
+                IntErrorKind::PosOverflow => {
+                    return Err(StrConversionError::TooLarge);
+                },
+                // not reachable after the check above, but an invalid string is the safe answer
+                _ => return Err(StrConversionError::InvalidString),
+            }
+        },
+        Ok(integer_part) => {
+            number.integer_part = Some(integer_part);
+        }
+    }
+
+    if let Some(floating_point_index) = floating_point_index_option {
+        number.fractional_part = Some(string[floating_point_index + 1..].into());
+    }
+
+    // negative zero ("-0", "-0.00") is read as plain zero
+    let zero_fractional_part = number.fractional_part.as_deref().map_or(true, |digits| digits.bytes().all(|byte| byte == b'0'));
+    if number.integer_part.unwrap_or(0) == 0 && zero_fractional_part {
+        number.negative = false;
+    }
+
+    return Ok(number);
+}
+
+/// Splits a leading `'-'` or `'+'` off a number string, returning whether the number is negative.
+#[allow(clippy::needless_return)]
+fn split_sign(string: &str) -> (bool, &str) {
+    if let Some(rest) = string.strip_prefix('-') {
+        return (true, rest);
+    }
+    return (false, string.strip_prefix('+').unwrap_or(string));
+}
+
+
+/// Replaces words in already spelled number words according to a table of overrides.
+///
+/// # Arguments
+/// - `words`: `&str` of number words, as returned by any of the conversion functions.
+/// - `overrides`: Pairs of `(word, replacement)`. Only whole words are replaced; both halves of a
+///   hyphenated compound (***twenty-one***) count as separate words.
+///
+/// # Returns
+/// A [`String`] with every word that appears in `overrides` replaced. Spaces and hyphens are kept as they are.
+///
+/// # Example
+/// ```
+/// use num2en::override_words;
+///
+/// let overrides = [("negative", "minus"), ("one", "won")];
+/// assert_eq!(override_words("negative twenty-one", &overrides), "minus twenty-won");
+/// assert_eq!(override_words("nineteen", &overrides), "nineteen");
+/// ```
+///
+/// # Notes
+/// - If a word appears in `overrides` more than once, the first pair is used.
+#[allow(clippy::needless_return)]
+pub fn override_words(words: &str, overrides: &[(&str, &str)]) -> String {
+    let mut result = String::with_capacity(words.len());
+    let mut word_start = 0;
+    for (i, c) in words.char_indices().chain(core::iter::once((words.len(), ' '))) {
+        if c == ' ' || c == '-' {
+            let word = &words[word_start..i];
+            match overrides.iter().find(|(original, _)| *original == word) {
+                Some((_, replacement)) => result.push_str(replacement),
+                None => result.push_str(word),
+            }
+            if i < words.len() {
+                result.push(c);
+            }
+            word_start = i + c.len_utf8();
+        }
+    }
+    return result;
+}
+
+/// Same as [str_to_words], except that the words in the result are replaced according to `overrides`.
+///
+/// # Example
+/// ```
+/// use num2en::str_to_words_with_overrides;
+///
+/// let overrides = [("negative", "minus"), ("zero", "nought"), ("point", "dot")];
+/// let words = str_to_words_with_overrides("-0.05", &overrides);
+/// assert_eq!(words, Ok("minus nought dot nought five".to_string()));
+/// ```
+///
+/// # Notes
+/// - This function uses [str_to_words] and [override_words] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn str_to_words_with_overrides(string: &str, overrides: &[(&str, &str)]) -> Result<String, StrConversionError> {
+    return Ok(override_words(&str_to_words(string)?, overrides));
+}
+
+/// Same as [str_to_words], except that the integer part may be larger than `u128::MAX`, up to
+/// 66 digits (***nine hundred ninety-nine vigintillion ...***).
+///
+/// # Arguments
+/// - `string`: `&str` in the format accepted by [str_to_words].
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(String)`: The same words as [str_to_words] would return, if the number were supported by it.
+/// - `Err(StrConversionError)`: [StrConversionError::TooLarge] if the integer part has more than
+///   `3 * (`[MAX_SUPPORTED_PERIODS]` + 1)` digits (without leading zeros), otherwise the same errors as [str_to_words].
+///
+/// # Example
+/// ```
+/// use num2en::str_to_words_large;
+/// # use num2en::StrConversionError;
+///
+/// let words = str_to_words_large("1000000000000000000000000000000000000000");
+/// assert_eq!(words, Ok("one duodecillion".to_string()));
+///
+/// let words = str_to_words_large("-2e63");
+/// assert_eq!(words, Ok("negative two vigintillion".to_string()));
+///
+/// assert_eq!(str_to_words_large("1e66"), Err(StrConversionError::TooLarge));
+/// ```
+///
+/// # Notes
+/// - This function uses [str_to_words] behind the curtains for numbers it supports.
+#[allow(clippy::needless_return)]
+pub fn str_to_words_large(string: &str) -> Result<String, StrConversionError> {
+    match str_to_words(string) {
+        Err(StrConversionError::TooLarge) => {},
+        result => return result,
+    }
+
+    // Only the integer part (or the exponent) can be too large here, and the string is otherwise valid
+    let mut expanded = strip_digit_grouping(string)?;
+    if expanded.contains(|c| c == 'e' || c == 'E') {
+        expanded = expand_scientific_notation(&expanded)?;
+    }
+    let (negative, unsigned) = split_sign(&expanded);
+    let (integer_digits, fractional_digits) = match unsigned.split_once('.') {
+        Some((integer_digits, fractional_digits)) => (integer_digits, Some(fractional_digits)),
+        None => (unsigned, None),
+    };
+    let integer_digits = integer_digits.trim_start_matches('0');
+    if integer_digits.len() > 3 * (MAX_SUPPORTED_PERIODS + 1) {
+        return Err(StrConversionError::TooLarge);
+    }
+
+    let mut words = Vec::<String>::new();
+    if negative {
+        words.push("negative".to_string());
+    }
+    let first_group_len = (integer_digits.len() - 1) % 3 + 1;
+    let mut start = 0;
+    let mut end = first_group_len;
+    while end <= integer_digits.len() {
+        let group = integer_digits[start..end].parse::<u16>().unwrap();
+        if group != 0 {
+            lt1000(group, &DEFAULT_WORDS_OPTIONS, &mut words);
+            let idx = (integer_digits.len() - end) / 3;
+            if idx > 0 {
+                push_period(idx - 1, &mut words);
+            }
+        }
+        start = end;
+        end += 3;
+    }
+    if let Some(fractional_digits) = fractional_digits {
+        words.push("point".to_string());
+        if !fractional_digits.is_empty() {
+            words.push(str_digits_to_words(fractional_digits).unwrap());
+        }
+    }
+
+    return Ok(words.join(" "));
+}
+
+/// Same as [str_to_words], except that a repeating group of fractional digits can be marked with
+/// parentheses (`"0.1(6)"`) and is read with ***repeating*** (***zero point one six repeating***).
+///
+/// # Arguments
+/// - `string`: `&str` in the format accepted by [str_to_words], optionally ending with a
+///   parenthesized group of one or more digits after the decimal point.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(String)`: The number in words, followed by ***repeating*** if it has a repeating group.
+/// - `Err(StrConversionError)`: An error returned by [str_to_words], or [StrConversionError::InvalidString]
+///   if the parentheses are malformed, empty, not after the decimal point, or not at the end.
+///
+/// # Example
+/// ```
+/// use num2en::str_to_words_repeating;
+/// # use num2en::StrConversionError;
+///
+/// assert_eq!(str_to_words_repeating("0.(3)"), Ok("zero point three repeating".to_string()));
+/// assert_eq!(str_to_words_repeating("0.1(6)"), Ok("zero point one six repeating".to_string()));
+/// assert_eq!(str_to_words_repeating("0.25"), Ok("zero point two five".to_string()));
+/// assert_eq!(str_to_words_repeating("0.(3"), Err(StrConversionError::InvalidString));
+/// ```
+#[allow(clippy::needless_return)]
+pub fn str_to_words_repeating(string: &str) -> Result<String, StrConversionError> {
+    let open = match string.find('(') {
+        Some(open) => open,
+        None => return str_to_words(string),
+    };
+
+    let repetend = string[open + 1..].strip_suffix(')').ok_or(StrConversionError::InvalidString)?;
+    let fixed = &string[..open];
+    if repetend.is_empty() || !repetend.bytes().all(|byte| byte.is_ascii_digit()) || !fixed.contains('.') {
+        return Err(StrConversionError::InvalidString);
+    }
+
+    return Ok(str_to_words(&(fixed.to_string() + repetend))? + " repeating");
+}
+
+/// Converts a percentage (`"12.5%"`) to words (***twelve point five percent***).
+///
+/// # Arguments
+/// - `string`: `&str` of a number in the format accepted by [str_to_words], followed by a single `%`.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(String)`: The number in words, followed by ***percent***.
+/// - `Err(StrConversionError)`: An error returned by [str_to_words], or [StrConversionError::InvalidString]
+///   if the string does not end with `%` or has no number before it.
+///
+/// # Example
+/// ```
+/// use num2en::str_percent_to_words;
+/// # use num2en::StrConversionError;
+///
+/// assert_eq!(str_percent_to_words("12.5%"), Ok("twelve point five percent".to_string()));
+/// assert_eq!(str_percent_to_words("-3%"), Ok("negative three percent".to_string()));
+/// assert_eq!(str_percent_to_words("100"), Err(StrConversionError::InvalidString));
+/// ```
+///
+/// # Notes
+/// - The `%` is required; a plain number is rejected rather than read as an implicit percentage.
+#[allow(clippy::needless_return)]
+pub fn str_percent_to_words(string: &str) -> Result<String, StrConversionError> {
+    let number = string.strip_suffix('%').ok_or(StrConversionError::InvalidString)?;
+    if number.is_empty() {
+        return Err(StrConversionError::InvalidString);
+    }
+    return Ok(str_to_words(number)? + " percent");
+}
+
+/// Converts a percentage range (`"10-20%"`) to words (***ten to twenty percent***).
+///
+/// # Arguments
+/// - `string`: `&str` made of two numbers separated by `-` and followed by a single `%`.
+///   Each number has the format accepted by [str_to_words], so `"-5--2.5%"` is a valid range.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(String)`: Both bounds in words, joined by ***to*** and followed by ***percent***.
+/// - `Err(StrConversionError)`: An error returned by [str_to_words] for either bound, or
+///   [StrConversionError::InvalidString] if the string does not end with `%` or has no range separator.
+///
+/// # Example
+/// ```
+/// use num2en::percent_range_words;
+/// # use num2en::StrConversionError;
+///
+/// assert_eq!(percent_range_words("10-20%"), Ok("ten to twenty percent".to_string()));
+/// assert_eq!(percent_range_words("-2.5-5%"), Ok("negative two point five to five percent".to_string()));
+/// assert_eq!(percent_range_words("10%-20%"), Err(StrConversionError::InvalidString));
+/// ```
+#[allow(clippy::needless_return)]
+pub fn percent_range_words(string: &str) -> Result<String, StrConversionError> {
+    let range = string.strip_suffix('%').ok_or(StrConversionError::InvalidString)?;
+
+    // the range separator is the first '-' that follows the first bound, i.e. a digit or a '.'
+    let bytes = range.as_bytes();
+    let separator = (1..bytes.len())
+        .find(|&i| bytes[i] == b'-' && (bytes[i - 1].is_ascii_digit() || bytes[i - 1] == b'.'))
+        .ok_or(StrConversionError::InvalidString)?;
+
+    let (from, to) = (&range[..separator], &range[separator + 1..]);
+    if to.is_empty() {
+        return Err(StrConversionError::InvalidString);
+    }
+    let from = str_to_words(from)?;
+    let to = str_to_words(to)?;
+    return Ok(from + " to " + &to + " percent");
+}
+
+/// Replaces every full-width digit (`０`-`９`, U+FF10 to U+FF19) in a string with its ASCII counterpart.
+///
+/// # Arguments
+/// - `string`: `&str` which may contain full-width digits, as typed by some CJK input methods.
+///
+/// # Returns
+/// A [`String`] where all full-width digits are replaced with `0`-`9`. All other characters are left untouched.
+///
+/// # Example
+/// ```
+/// use num2en::normalize_fullwidth_digits;
+///
+/// assert_eq!(normalize_fullwidth_digits("１２３.４"), "123.4");
+/// ```
+pub fn normalize_fullwidth_digits(string: &str) -> String {
+    string.chars()
+        .map(|c| match c {
+            '\u{FF10}'..='\u{FF19}' => (b'0' + (c as u32 - 0xFF10) as u8) as char,
+            _ => c,
+        })
+        .collect()
+}
+
+/// Same as [str_digits_to_words], except that full-width digits (`０`-`９`) are accepted as well.
+///
+/// # Example
+/// ```
+/// use num2en::{str_digits_to_words, str_digits_to_words_fullwidth, DigitConversionError};
+///
+/// assert_eq!(str_digits_to_words_fullwidth("１２３"), Ok("one two three".to_string()));
+/// assert_eq!(str_digits_to_words("１２３"), Err(DigitConversionError::InvalidCharacter));
+/// ```
+///
+/// # Notes
+/// - This function uses [normalize_fullwidth_digits] and [str_digits_to_words] behind the curtains.
+pub fn str_digits_to_words_fullwidth(digits: &str) -> Result<String, DigitConversionError> {
+    str_digits_to_words(&normalize_fullwidth_digits(digits))
+}
+
+/// Same as [str_to_words], except that full-width digits (`０`-`９`) are accepted as well.
+///
+/// # Example
+/// ```
+/// use num2en::{str_to_words, str_to_words_fullwidth, StrConversionError};
+///
+/// assert_eq!(str_to_words_fullwidth("１２３"), Ok("one hundred twenty-three".to_string()));
+/// assert_eq!(str_to_words("１２３"), Err(StrConversionError::InvalidString));
+/// ```
+///
+/// # Notes
+/// - Only the digits are normalized; the sign and the decimal point must still be ASCII `-` and `.`.
+/// - This function uses [normalize_fullwidth_digits] and [str_to_words] behind the curtains.
+pub fn str_to_words_fullwidth(string: &str) -> Result<String, StrConversionError> {
+    str_to_words(&normalize_fullwidth_digits(string))
+}
+
+#[derive(Debug, PartialEq)]
+/// Represents the possible errors that can occur when calling [f32_to_words] or [f64_to_words].
+pub enum FloatConversionError {
+    /// Indicates that the value is not finite (i.e., it is either `NaN`, positive infinity, or negative infinity).
+    NotFinite,
+    /// Indicates that the value is too large to be converted.
+    TooLarge,
+}
+
+impl FloatConversionError {
+    /// Returns a short human-readable description of the error.
+    pub fn message(&self) -> &'static str {
+        match self {
+            FloatConversionError::NotFinite => "number is not finite",
+            FloatConversionError::TooLarge => "number is too large to convert (max is 2^128 - 1)",
+        }
+    }
+}
+
+impl core::fmt::Display for FloatConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FloatConversionError {}
+
+#[derive(Debug, PartialEq)]
+/// Represents an error returned by any of the conversion functions, so that `?` can be used with
+/// functions returning different error types.
+///
+/// # Example
+/// ```
+/// use num2en::{str_digits_to_words, str_to_words, ConversionError, StrConversionError};
+///
+/// fn pin_and_amount(pin: &str, amount: &str) -> Result<String, ConversionError> {
+///     return Ok(str_digits_to_words(pin)? + ", " + &str_to_words(amount)?);
+/// }
+///
+/// assert_eq!(pin_and_amount("042", "1.5"), Ok("zero four two, one point five".to_string()));
+/// assert_eq!(pin_and_amount("042", "1.5.0"), Err(ConversionError::Str(StrConversionError::InvalidString)));
+/// ```
+pub enum ConversionError {
+    /// An error returned by [str_digits_to_words] and similar functions.
+    Digit(DigitConversionError),
+    /// An error returned by [u32_to_padded_digit_words].
+    PaddedDigit(PaddedDigitConversionError),
+    /// An error returned by [str_to_words] and similar functions.
+    Str(StrConversionError),
+    /// An error returned by [f32_to_words], [f64_to_words] and similar functions.
+    Float(FloatConversionError),
+    /// An error returned by [bearing_to_words] and [bearing_to_words_with].
+    Bearing(BearingConversionError),
+    /// An error returned by [day_ordinal_words] and similar functions.
+    Day(DayConversionError),
+    /// An error returned by [to_usd_words] and similar functions.
+    Currency(CurrencyConversionError),
+    /// An error returned by [words_to_u128] and similar functions.
+    Words(WordsParseError),
+    /// An error returned by [u16_to_roman] and [u16_to_roman_lowercase].
+    Roman(RomanError),
+    /// An error returned by [to_words_with_groups].
+    Grouping(GroupingError),
+    /// An error returned by [base_narration].
+    Radix(RadixError),
+}
+
+impl ConversionError {
+    /// Returns a short human-readable description of the error.
+    pub fn message(&self) -> &'static str {
+        match self {
+            ConversionError::Digit(error) => error.message(),
+            ConversionError::PaddedDigit(error) => error.message(),
+            ConversionError::Str(error) => error.message(),
+            ConversionError::Float(error) => error.message(),
+            ConversionError::Bearing(error) => error.message(),
+            ConversionError::Day(error) => error.message(),
+            ConversionError::Currency(error) => error.message(),
+            ConversionError::Words(error) => error.message(),
+            ConversionError::Roman(error) => error.message(),
+            ConversionError::Grouping(error) => error.message(),
+            ConversionError::Radix(error) => error.message(),
+        }
+    }
+}
+
+impl core::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+impl From<DigitConversionError> for ConversionError {
+    fn from(error: DigitConversionError) -> Self {
+        ConversionError::Digit(error)
+    }
+}
+
+impl From<PaddedDigitConversionError> for ConversionError {
+    fn from(error: PaddedDigitConversionError) -> Self {
+        ConversionError::PaddedDigit(error)
+    }
+}
+
+impl From<StrConversionError> for ConversionError {
+    fn from(error: StrConversionError) -> Self {
+        ConversionError::Str(error)
+    }
+}
+
+impl From<FloatConversionError> for ConversionError {
+    fn from(error: FloatConversionError) -> Self {
+        ConversionError::Float(error)
+    }
+}
+
+impl From<BearingConversionError> for ConversionError {
+    fn from(error: BearingConversionError) -> Self {
+        ConversionError::Bearing(error)
+    }
+}
+
+impl From<DayConversionError> for ConversionError {
+    fn from(error: DayConversionError) -> Self {
+        ConversionError::Day(error)
+    }
+}
+
+impl From<CurrencyConversionError> for ConversionError {
+    fn from(error: CurrencyConversionError) -> Self {
+        ConversionError::Currency(error)
+    }
+}
+
+impl From<WordsParseError> for ConversionError {
+    fn from(error: WordsParseError) -> Self {
+        ConversionError::Words(error)
+    }
+}
+
+impl From<RomanError> for ConversionError {
+    fn from(error: RomanError) -> Self {
+        ConversionError::Roman(error)
+    }
+}
+
+impl From<GroupingError> for ConversionError {
+    fn from(error: GroupingError) -> Self {
+        ConversionError::Grouping(error)
+    }
+}
+
+impl From<RadixError> for ConversionError {
+    fn from(error: RadixError) -> Self {
+        ConversionError::Radix(error)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents how a float zero with its sign bit set (`-0.0`) is read.
+pub enum SignedZero {
+    /// `-0.0` is read the same as `0.0` (***zero***). This is the default.
+    Suppress,
+    /// `-0.0` is read as ***negative zero***, so positive and negative zero stay distinguishable.
+    Preserve,
+}
+
+macro_rules! create_public_conversion_func_of_float {
+    ( $t:ty, $name:ident, $name_with_signed_zero:ident ) => {
+        /// Converts any*
+        #[doc = concat!("`", stringify!($t), "`")]
+        /// value of a number to a number representation in words.
+        ///
+        /// # Arguments
+        /// - `float`: A float
+        #[doc = concat!("(`", stringify!($t), "`)")]
+        /// that represents the number to be converted.
+        /// <br> * The number must be 2<sup>128</sup> - 1 (~ 340 undecillion) or smaller,
+        /// otherwise a [TooLarge](FloatConversionError::TooLarge) error gets returned.
+        ///
+        /// # Returns
+        /// [`Result`]`<`[`String`]`, `[`FloatConversionError`]`>`
+        /// 
+        /// The string contains the English words that represent the input number.
+        /// 
+        /// For example, `"123.456"` becomes `"one hundred twenty-three point four five six"`.
+        ///
+        #[doc = concat!(
+            "# Examples\n\
+            ```\n\
+            use num2en::", stringify!($name), ";\n\
+            # use num2en::FloatConversionError;\n\n\
+            let number = 123.123;\n\
+            let result = ", stringify!($name), "(number);\n\
+            assert_eq!(result, Ok(\"one hundred twenty-three point one two three\".to_string()));\n\n\
+            let number = 4e-5;\n\
+            let result = ", stringify!($name), "(number);\n\
+            assert_eq!(result, Ok(\"zero point zero zero zero zero four\".to_string()));\n\n\
+            let number = 34.000;\n\
+            let result = ", stringify!($name), "(number);\n\
+            assert_eq!(result, Ok(\"thirty-four\".to_string()));\n\n\
+            let infinity = ", stringify!($t), "::INFINITY;\n\
+            let result = ", stringify!($name), "(infinity);\n\
+            assert_eq!(result, Err(FloatConversionError::NotFinite));\n\n\
+            let not_a_number = ", stringify!($t), "::NAN;\n\
+            let result = ", stringify!($name), "(not_a_number);\n\
+            assert_eq!(result, Err(FloatConversionError::NotFinite));\n\
+            ```"
+        )]
+        /// 
+        /// # Notes
+        /// - This function supports only numbers between `-u128::MAX-1` (exclusive) and `u128::MAX+1` (exclusive).
+        /// - The function includes hyphens for numbers between 21 and 99 (e.g., "twenty-one").
+        /// - `-0.0` is read as ***zero*** ([SignedZero::Suppress]). Use
+        #[doc = concat!("[", stringify!($name_with_signed_zero), "]")]
+        /// with [SignedZero::Preserve] to read it as ***negative zero***.
+        /// - This function uses [str_to_words] behind the curtains.
+        pub fn $name(float: $t) -> Result<String, FloatConversionError> {
+            $name_with_signed_zero(float, SignedZero::Suppress)
+        }
+
+        /// Same as
+        #[doc = concat!("[", stringify!($name), "],")]
+        /// except that `signed_zero` determines how a zero with its sign bit set is read.
+        ///
+        #[doc = concat!(
+            "# Example\n\
+            ```\n\
+            use num2en::{", stringify!($name_with_signed_zero), ", SignedZero};\n\n\
+            let result = ", stringify!($name_with_signed_zero), "(-0.0, SignedZero::Preserve);\n\
+            assert_eq!(result, Ok(\"negative zero\".to_string()));\n\n\
+            let result = ", stringify!($name_with_signed_zero), "(-0.0, SignedZero::Suppress);\n\
+            assert_eq!(result, Ok(\"zero\".to_string()));\n\n\
+            let result = ", stringify!($name_with_signed_zero), "(0.0, SignedZero::Preserve);\n\
+            assert_eq!(result, Ok(\"zero\".to_string()));\n\
+            ```"
+        )]
+        pub fn $name_with_signed_zero(float: $t, signed_zero: SignedZero) -> Result<String, FloatConversionError> {
+            if !float.is_finite() {
+                return Err(FloatConversionError::NotFinite);
+            }
+            return float_str_to_words(&float.to_string(), float.is_sign_negative(), signed_zero, &DEFAULT_WORDS_OPTIONS);
+        }
+    };
+}
+
+/// Spells `float_string`, the formatted form of a finite float whose sign bit is `sign_negative`.
+/// A negative value that is (or was rounded to) zero is read as negative only with [SignedZero::Preserve].
+#[allow(clippy::needless_return)]
+fn float_str_to_words(
+    float_string: &str,
+    sign_negative: bool,
+    signed_zero: SignedZero,
+    options: &WordsOptions,
+) -> Result<String, FloatConversionError> {
+    let mut number = match parse_number_str(float_string) {
+        Err(StrConversionError::TooLarge) => return Err(FloatConversionError::TooLarge),
+        Err(StrConversionError::InvalidString) => unreachable!(),
+        Ok(number) => number,
+    };
+    // parse_number_str reads "-0" and "-0.00" as plain zero
+    if sign_negative && signed_zero == SignedZero::Preserve {
+        number.negative = true;
+    }
+    return options.number_str_to_words(&number).map_err(|_| FloatConversionError::TooLarge);
+}
+
+create_public_conversion_func_of_float!(f32, f32_to_words, f32_to_words_with_signed_zero);
+create_public_conversion_func_of_float!(f64, f64_to_words, f64_to_words_with_signed_zero);
+
+
+/// Parses a non-empty string of ASCII digits.
+#[allow(clippy::needless_return)]
+fn parse_digits(digits: &str) -> Result<u128, StrConversionError> {
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(StrConversionError::InvalidString);
+    }
+    return digits.parse::<u128>().map_err(|_| StrConversionError::TooLarge);
+}
+
+/// Spells the name of the fraction `1/denominator`, in plural if `plural` is set
+/// (e.g. ***half***, ***quarters***, ***thirds***, ***hundredths***).
+#[allow(clippy::needless_return)]
+fn denominator_words(denominator: u128, plural: bool) -> String {
+    let mut words = match denominator {
+        2 => "half".to_string(),
+        4 => "quarter".to_string(),
+        _ => u128_to_ord_words(denominator),
+    };
+    // "one hundredth", not "one one hundredth"
+    let mut power_of_ten = denominator;
+    while power_of_ten >= 10 && power_of_ten % 10 == 0 {
+        power_of_ten /= 10;
+    }
+    if power_of_ten == 1 && words.starts_with("one ") {
+        words.replace_range(..4, "");
+    }
+    // compound denominators are hyphenated ("ten-thousandth")
+    words = words.replace(' ', "-");
+    if plural {
+        if denominator == 2 {
+            return "halves".to_string();
+        }
+        words.push('s');
+    }
+    return words;
+}
+
+/// Spells the fraction `numerator/denominator` (e.g. ***one half***, ***three quarters***).
+/// <br> If `article` is set, a numerator of one is read as the [indefinite_article] of the
+/// denominator (e.g. ***a half***, ***an eighth***).
+#[allow(clippy::needless_return)]
+fn fraction_words(numerator: u128, denominator: u128, article: bool) -> String {
+    let denominator_words = denominator_words(denominator, numerator != 1);
+    let numerator_words = if numerator == 1 && article {
+        indefinite_article(&denominator_words).to_string()
+    } else {
+        u128_to_words(numerator)
+    };
+    return numerator_words + " " + &denominator_words;
+}
+
+/// Converts any* string of a (decimal) number to words, reading the fractional part as a fraction
+/// over a power of ten (***three and fourteen hundredths***) instead of with ***point***.
+///
+/// # Arguments
+/// - `string`: `&str` representing a number in the `... xxxxxx.xxxxxx ...` format, where `x` is any digit.
+///   <br> * The same restrictions as in [str_to_words] apply, and the fractional part can have at most 38 digits.
+///
+/// # Returns
+/// [`Result`]`<`[`String`]`, `[`StrConversionError`]`>`
+///
+/// The string contains the integer part, ***and***, and the fractional digits read as a number over
+/// the matching (pluralized) ordinal denominator. A zero integer part or a zero fractional part is left out.
+///
+/// # Examples
+/// ```
+/// use num2en::str_to_words_decimal_denominator;
+/// # use num2en::StrConversionError;
+///
+/// assert_eq!(str_to_words_decimal_denominator("3.14"), Ok("three and fourteen hundredths".to_string()));
+/// assert_eq!(str_to_words_decimal_denominator("0.005"), Ok("five thousandths".to_string()));
+/// assert_eq!(str_to_words_decimal_denominator("2.1"), Ok("two and one tenth".to_string()));
+/// assert_eq!(str_to_words_decimal_denominator("-0.0125"), Ok("negative one hundred twenty-five ten-thousandths".to_string()));
+/// assert_eq!(str_to_words_decimal_denominator("7.00"), Ok("seven".to_string()));
+/// ```
+///
+/// # Notes
+/// - A fractional part with more than 38 digits results in a [TooLarge](StrConversionError::TooLarge) error.
+/// - This function uses [WordsOptions::fraction_style] with [FractionStyle::Denominator] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn str_to_words_decimal_denominator(string: &str) -> Result<String, StrConversionError> {
+    return WordsOptions::new().fraction_style(FractionStyle::Denominator).str_to_words(string);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents the group a number is counted in by [tally_words_with].
+pub enum TallyBase {
+    /// 12
+    Dozen,
+    /// 20
+    Score,
+    /// 144
+    Gross,
+}
+
+impl TallyBase {
+    fn size(self) -> u128 {
+        match self {
+            TallyBase::Dozen => 12,
+            TallyBase::Score => 20,
+            TallyBase::Gross => 144,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            TallyBase::Dozen => "dozen",
+            TallyBase::Score => "score",
+            TallyBase::Gross => "gross",
+        }
+    }
+}
+
+/// Converts `n` to words counted in dozens (***three and a half dozen***).
+///
+/// # Example
+/// ```
+/// use num2en::tally_words;
+///
+/// assert_eq!(tally_words(42), "three and a half dozen");
+/// ```
+///
+/// # Notes
+/// - This function uses [tally_words_with] with [TallyBase::Dozen] behind the curtains.
+pub fn tally_words(n: u128) -> String {
+    tally_words_with(n, TallyBase::Dozen)
+}
+
+/// Converts `n` to words counted in groups of `base` (***four score***, ***two and a quarter dozen***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+/// - `base`: The [`TallyBase`] the number is counted in.
+///
+/// # Returns
+/// A [`String`] containing the number of groups, followed by the name of the group.
+/// <br> If there is a remainder, it is read as ***and a half***, ***and a third***, ***and two thirds***,
+/// ***and a quarter*** or ***and three quarters*** of a group. If the remainder is none of those, or
+/// `n` is smaller than one group, the ordinary **cardinal** reading is returned instead.
+///
+/// # Examples
+/// ```
+/// use num2en::{tally_words_with, TallyBase};
+///
+/// assert_eq!(tally_words_with(80, TallyBase::Score), "four score");
+/// assert_eq!(tally_words_with(27, TallyBase::Dozen), "two and a quarter dozen");
+/// assert_eq!(tally_words_with(29, TallyBase::Dozen), "twenty-nine");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn tally_words_with(n: u128, base: TallyBase) -> String {
+    let whole = n / base.size();
+    let remainder = n % base.size();
+    if whole == 0 {
+        return u128_to_words(n);
+    }
+
+    let mut words = vec![u128_to_words(whole)];
+    if remainder != 0 {
+        let mut gcd = base.size();
+        let mut other = remainder;
+        while other != 0 {
+            let next = gcd % other;
+            gcd = other;
+            other = next;
+        }
+        let (numerator, denominator) = (remainder / gcd, base.size() / gcd);
+        if denominator > 4 {
+            return u128_to_words(n);
+        }
+        words.push("and".to_string());
+        words.push(fraction_words(numerator, denominator, true));
+    }
+    words.push(base.name().to_string());
+
+    return words.join(" ");
+}
+
+/// Converts a string of a mixed number (e.g. `"2 1/2"`) to words (***two and a half***).
+///
+/// # Arguments
+/// - `string`: `&str` representing a mixed number in the `[-]w n/d` format, where the whole part `w`
+///   and the fraction `n/d` are both optional (but not both missing) and are separated by a single space.
+///   <br> The denominator `d` must be at least 2.
+///
+/// # Returns
+/// [`Result`]`<`[`String`]`, `[`StrConversionError`]`>`
+///
+/// The string contains the whole part, ***and***, and the fraction, with denominators of 2 and 4 read
+/// as ***half*** and ***quarter*** and all other denominators read as ordinals.
+///
+/// # Examples
+/// ```
+/// use num2en::mixed_number_words;
+/// # use num2en::StrConversionError;
+///
+/// assert_eq!(mixed_number_words("2 1/2"), Ok("two and a half".to_string()));
+/// assert_eq!(mixed_number_words("1 3/4"), Ok("one and three quarters".to_string()));
+/// assert_eq!(mixed_number_words("5 2/3"), Ok("five and two thirds".to_string()));
+/// assert_eq!(mixed_number_words("1/2"), Ok("one half".to_string()));
+/// assert_eq!(mixed_number_words("7/16"), Ok("seven sixteenths".to_string()));
+/// assert_eq!(mixed_number_words("3"), Ok("three".to_string()));
+/// assert_eq!(mixed_number_words("-1 1/8"), Ok("negative one and an eighth".to_string()));
+///
+/// assert_eq!(mixed_number_words("1/0"), Err(StrConversionError::InvalidString));
+/// assert_eq!(mixed_number_words("2 1/2/3"), Err(StrConversionError::InvalidString));
+/// ```
+///
+/// # Notes
+/// - A numerator of one is read as ***a*** after a whole part (***an*** before ***eighth*** and
+///   ***eleventh***, see [indefinite_article]) and as ***one*** otherwise.
+/// - Like in [str_to_words], a leading `'+'` is accepted and a negative zero (`"-0"`, `"-0/2"`) is
+///   read without its sign.
+#[allow(clippy::needless_return)]
+pub fn mixed_number_words(string: &str) -> Result<String, StrConversionError> {
+    let (negative, string) = split_sign(string);
+    let mut words = Vec::<String>::new();
+    let mut zero = true;
+
+    let (whole_part, fraction) = match string.find(' ') {
+        Some(space_index) => (Some(&string[..space_index]), Some(&string[space_index + 1..])),
+        None if string.contains('/') => (None, Some(string)),
+        None => (Some(string), None),
+    };
+
+    if let Some(whole_part) = whole_part {
+        let whole_part = parse_digits(whole_part)?;
+        zero &= whole_part == 0;
+        words.push(u128_to_words(whole_part));
+    }
+
+    if let Some(fraction) = fraction {
+        let slash_index = fraction.find('/').ok_or(StrConversionError::InvalidString)?;
+        let numerator = parse_digits(&fraction[..slash_index])?;
+        let denominator = parse_digits(&fraction[slash_index + 1..])?;
+        if denominator < 2 {
+            return Err(StrConversionError::InvalidString);
+        }
+        zero &= numerator == 0;
+
+        if whole_part.is_some() {
+            words.push("and".to_string());
+        }
+        words.push(fraction_words(numerator, denominator, whole_part.is_some()));
+    }
+
+    // negative zero is read as plain zero, like in parse_number_str
+    if negative && !zero {
+        words.insert(0, "negative".to_string());
+    }
+    return Ok(words.join(" "));
+}
+
+
+/// Converts any* string of a (decimal) number to words, reading the fractional part as a common
+/// fraction (***two and a half***, ***three quarters***) where it matches one.
+///
+/// # Arguments
+/// - `string`: `&str` representing a number in the `... xxxxxx.xxxxxx ...` format, where `x` is any digit.
+///   <br> * The same restrictions as in [str_to_words] apply.
+///
+/// # Returns
+/// [`Result`]`<`[`String`]`, `[`StrConversionError`]`>`
+///
+/// If the fractional part (ignoring trailing zeros) is a number of halves, thirds, quarters, fifths or
+/// eighths, the string contains the integer part, ***and*** and the fraction (or just the fraction for
+/// a zero or missing integer part). Otherwise it is the same as [str_to_words].
+///
+/// # Examples
+/// ```
+/// use num2en::str_to_fraction_words;
+///
+/// assert_eq!(str_to_fraction_words("2.5"), Ok("two and a half".to_string()));
+/// assert_eq!(str_to_fraction_words("0.75"), Ok("three quarters".to_string()));
+/// assert_eq!(str_to_fraction_words("1.125"), Ok("one and an eighth".to_string()));
+/// assert_eq!(str_to_fraction_words("-.667"), Ok("negative two thirds".to_string()));
+/// assert_eq!(str_to_fraction_words("2.7"), Ok("two point seven".to_string()));
+/// ```
+///
+/// # Notes
+/// - Thirds are recognized from at least two digits: ***one third*** from `33`, `333`, ...
+///   and ***two thirds*** from `66`, `67`, `666`, `667`, ...
+#[allow(clippy::needless_return)]
+pub fn str_to_fraction_words(string: &str) -> Result<String, StrConversionError> {
+    let number = parse_number_str(string)?;
+    let digits = number.fractional_part.as_deref().unwrap_or("").trim_end_matches('0');
+
+    let fraction = match digits {
+        "5" => Some((1, 2)),
+        "25" => Some((1, 4)),
+        "75" => Some((3, 4)),
+        "2" => Some((1, 5)),
+        "4" => Some((2, 5)),
+        "6" => Some((3, 5)),
+        "8" => Some((4, 5)),
+        "125" => Some((1, 8)),
+        "375" => Some((3, 8)),
+        "625" => Some((5, 8)),
+        "875" => Some((7, 8)),
+        _ if digits.len() >= 2 && digits.bytes().all(|digit| digit == b'3') => Some((1, 3)),
+        _ if digits.len() >= 2
+            && digits[..digits.len() - 1].bytes().all(|digit| digit == b'6')
+            && (digits.ends_with('6') || digits.ends_with('7')) => Some((2, 3)),
+        _ => None,
+    };
+    let (numerator, denominator) = match fraction {
+        Some(fraction) => fraction,
+        None => return str_to_words(string),
+    };
+
+    let mut words = Vec::<String>::new();
+    if number.negative {
+        words.push("negative".to_string());
+    }
+    match number.integer_part {
+        Some(integer_part) if integer_part != 0 => {
+            words.push(u128_to_words(integer_part));
+            words.push("and".to_string());
+            words.push(fraction_words(numerator, denominator, true));
+        },
+        _ => words.push(fraction_words(numerator, denominator, false)),
+    }
+    return Ok(words.join(" "));
+}
+
+/// Converts a count `n` of some items to words, followed by the singular or plural form of the noun.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the count.
+/// - `singular`: The noun used when `n` is exactly 1.
+/// - `plural`: The noun used for any other `n`.
+///
+/// # Returns
+/// A [`String`] containing the spelled count and the noun.
+///
+/// # Examples
+/// ```
+/// use num2en::count_to_words;
+///
+/// assert_eq!(count_to_words(0, "result", "results"), "zero results");
+/// assert_eq!(count_to_words(1, "result", "results"), "one result");
+/// assert_eq!(count_to_words(42, "mouse", "mice"), "forty-two mice");
+/// ```
+pub fn count_to_words(n: u128, singular: &str, plural: &str) -> String {
+    count_to_words_fn(n, |count| if count == 1 { singular } else { plural })
+}
+
+/// Converts a count `n` of some items to words, followed by the form of the noun chosen by `noun`.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the count.
+/// - `noun`: A function which receives `n` and returns the form of the noun to be used.
+///
+/// # Returns
+/// A [`String`] containing the spelled count and the chosen noun.
+///
+/// # Example
+/// ```
+/// use num2en::count_to_words_fn;
+///
+/// let mice = |count| if count == 1 { "mouse" } else { "mice" };
+/// assert_eq!(count_to_words_fn(1, mice), "one mouse");
+/// assert_eq!(count_to_words_fn(2, mice), "two mice");
+///
+/// let cattle = |count| match count { 0 => "cattle at all", 1 => "cow", _ => "cattle" };
+/// assert_eq!(count_to_words_fn(0, cattle), "zero cattle at all");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn count_to_words_fn<'a, F>(n: u128, noun: F) -> String
+where
+    F: Fn(u128) -> &'a str,
+{
+    return u128_to_words(n) + " " + noun(n);
+}
+
+/// Converts a count `n` of some items to words like [count_to_words], except that a count of zero
+/// is spelled as ***no*** (e.g. ***no results***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the count.
+/// - `singular`: The noun used when `n` is exactly 1.
+/// - `plural`: The noun used for any other `n`.
+///
+/// # Returns
+/// A [`String`] containing the spelled count (or ***no***) and the noun.
+///
+/// # Examples
+/// ```
+/// use num2en::count_or_none;
+///
+/// assert_eq!(count_or_none(0, "result", "results"), "no results");
+/// assert_eq!(count_or_none(1, "result", "results"), "one result");
+/// assert_eq!(count_or_none(3, "result", "results"), "three results");
+/// ```
+///
+/// # Notes
+/// - Use [count_or_zero_word] to choose a word other than ***no***.
+pub fn count_or_none(n: u128, singular: &str, plural: &str) -> String {
+    count_or_zero_word(n, "no", singular, plural)
+}
+
+/// Converts a count `n` of some items to words like [count_to_words], except that a count of zero
+/// is spelled as `zero_word`.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the count.
+/// - `zero_word`: The word used instead of ***zero*** when `n` is 0.
+/// - `singular`: The noun used when `n` is exactly 1.
+/// - `plural`: The noun used for any other `n`.
+///
+/// # Returns
+/// A [`String`] containing the spelled count (or `zero_word`) and the noun.
+///
+/// # Example
+/// ```
+/// use num2en::count_or_zero_word;
+///
+/// assert_eq!(count_or_zero_word(0, "nil", "file", "files"), "nil files");
+/// assert_eq!(count_or_zero_word(2, "nil", "file", "files"), "two files");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn count_or_zero_word(n: u128, zero_word: &str, singular: &str, plural: &str) -> String {
+    if n == 0 {
+        return zero_word.to_string() + " " + plural;
+    }
+    return count_to_words(n, singular, plural);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents the plural category of a count, as returned by [plural_category].
+pub enum PluralCategory {
+    /// The count is zero.
+    Zero,
+    /// The count is one.
+    One,
+    /// Any other count.
+    Other,
+}
+
+/// Returns the plural category of the count `n`, for choosing between message variants.
+///
+/// # Example
+/// ```
+/// use num2en::{plural_category, PluralCategory};
+///
+/// assert_eq!(plural_category(0), PluralCategory::Zero);
+/// assert_eq!(plural_category(1), PluralCategory::One);
+/// assert_eq!(plural_category(2), PluralCategory::Other);
+/// ```
+///
+/// # Notes
+/// - Unlike the English CLDR rules, zero has its own category, so that messages like
+///   ***no new messages*** can be selected.
+#[allow(clippy::needless_return)]
+pub fn plural_category(n: u128) -> PluralCategory {
+    match n {
+        0 => return PluralCategory::Zero,
+        1 => return PluralCategory::One,
+        _ => return PluralCategory::Other,
+    }
+}
+
+/// Selects the message matching the [plural_category] of `n` and replaces every `{n}` in it with
+/// `n` in words.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the count.
+/// - `zero_msg`, `one_msg`, `other_msg`: The messages for each [PluralCategory].
+///
+/// # Returns
+/// A [`String`] containing the selected message with the placeholders filled in.
+///
+/// # Example
+/// ```
+/// use num2en::select_message;
+///
+/// let message = |n| select_message(
+///     n,
+///     "You have no new messages",
+///     "You have {n} new message",
+///     "You have {n} new messages",
+/// );
+/// assert_eq!(message(0), "You have no new messages");
+/// assert_eq!(message(1), "You have one new message");
+/// assert_eq!(message(3), "You have three new messages");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn select_message(n: u128, zero_msg: &str, one_msg: &str, other_msg: &str) -> String {
+    let message = match plural_category(n) {
+        PluralCategory::Zero => zero_msg,
+        PluralCategory::One => one_msg,
+        PluralCategory::Other => other_msg,
+    };
+    return message.replace("{n}", &u128_to_words(n));
+}
+
+/// Word beginnings that start with a vowel letter but a consonant sound.
+const CONSONANT_SOUND_PREFIXES: [&str; 9] = ["one", "once", "uni", "use", "usu", "ute", "uro", "eu", "ewe"];
+/// Word beginnings that start with a vowel sound despite their letters (checked before
+/// [CONSONANT_SOUND_PREFIXES], so ***unimportant*** and ***uninformed*** are not read like ***unicorn***).
+const VOWEL_SOUND_PREFIXES: [&str; 7] = ["hour", "honest", "honor", "heir", "unim", "unin", "unidentif"];
+
+/// Chooses the indefinite article (***a*** or ***an***) that goes before `word`.
+///
+/// # Returns
+/// `"an"` if `word` starts with a vowel sound, `"a"` otherwise.
+///
+/// # Example
+/// ```
+/// use num2en::indefinite_article;
+///
+/// assert_eq!(indefinite_article("apple"), "an");
+/// assert_eq!(indefinite_article("eleventh"), "an");
+/// assert_eq!(indefinite_article("unicorn"), "a");
+/// assert_eq!(indefinite_article("hour"), "an");
+/// assert_eq!(indefinite_article("8-year-old"), "an");
+/// assert_eq!(indefinite_article("1"), "a");
+/// ```
+///
+/// # Notes
+/// - If `word` starts with digits, the choice is based on the leading word of the spelled number
+///   (***an 80***, read ***an eighty***, but ***a 1,000***, read ***a one thousand***).
+/// - Otherwise, the choice is based on the first letter of `word`, with a short list of exceptions
+///   (***one***, ***uni...***, ***eu...***, ***hour***, ***honest***, ...). Other irregular
+///   words may get the wrong article.
+#[allow(clippy::needless_return)]
+pub fn indefinite_article(word: &str) -> &'static str {
+    if word.starts_with(|c: char| c.is_ascii_digit()) {
+        return indefinite_article(&leading_number_word(word));
+    }
+
+    let word = word.to_lowercase();
+    if VOWEL_SOUND_PREFIXES.iter().any(|prefix| word.starts_with(prefix)) {
+        return "an";
+    }
+    if CONSONANT_SOUND_PREFIXES.iter().any(|prefix| word.starts_with(prefix)) {
+        return "a";
+    }
+    match word.chars().next() {
+        Some('a' | 'e' | 'i' | 'o' | 'u') => return "an",
+        _ => return "a",
+    }
+}
+
+/// Returns the first word of the number written with the leading digits (and grouping commas)
+/// of `word`, e.g. ***eleven*** for `"11,000th"`.
+#[allow(clippy::needless_return)]
+fn leading_number_word(word: &str) -> String {
+    let digits = word
+        .chars()
+        .take_while(|&c| c.is_ascii_digit() || c == ',')
+        .filter(|&c| c != ',')
+        .skip_while(|&c| c == '0')
+        .collect::<String>();
+    if digits.is_empty() {
+        return "zero".to_string();
+    }
+
+    // The leading word of the number is the leading word of its most significant period.
+    let leading_period_len = (digits.len() - 1) % 3 + 1;
+    let leading_period = digits[.. leading_period_len].parse::<u16>().unwrap();
+    let mut words = Vec::new();
+    lt1000(leading_period, &DEFAULT_WORDS_OPTIONS, &mut words);
+    return words[0].clone();
+}
+
+/// Spells the count `n` before `next_word`, using the indefinite article instead of ***one***
+/// where English does (***an apple***, ***a hundred sheep***, ***two owls***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the count.
+/// - `next_word`: The word that follows the count, used as is.
+///
+/// # Returns
+/// A [`String`] containing the count and `next_word`. A count of one is replaced by ***a*** or
+/// ***an*** chosen from `next_word`, and a leading ***one*** before ***hundred*** or a period
+/// name is replaced by ***a***.
+///
+/// # Example
+/// ```
+/// use num2en::spell_with_article;
+///
+/// assert_eq!(spell_with_article(1, "apple"), "an apple");
+/// assert_eq!(spell_with_article(1, "unicorn"), "a unicorn");
+/// assert_eq!(spell_with_article(100, "sheep"), "a hundred sheep");
+/// assert_eq!(spell_with_article(1_500, "years"), "a thousand five hundred years");
+/// assert_eq!(spell_with_article(11, "owls"), "eleven owls");
+/// ```
+///
+/// # Notes
+/// - The article is chosen by [indefinite_article].
+#[allow(clippy::needless_return)]
+pub fn spell_with_article(n: u128, next_word: &str) -> String {
+    if n == 1 {
+        return indefinite_article(next_word).to_string() + " " + next_word;
+    }
+
+    let mut words = u128_to_words_tokens(n);
+    if words.len() > 1 && words[0] == "one" {
+        words[0] = indefinite_article(&words[1]).to_string();
+    }
+    words.push(next_word.to_string());
+    return words.join(" ");
+}
+
+
+/// Converts `n` to words if it is at most `max_spelled`, and to its plain decimal digits otherwise.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+/// - `max_spelled`: The largest number that is still spelled out.
+///
+/// # Returns
+/// A [`String`] containing either the **cardinal** number words or the decimal digits of `n`.
+///
+/// # Example
+/// ```
+/// use num2en::spell_or_digits;
+///
+/// // "Spell out numbers under one hundred."
+/// assert_eq!(spell_or_digits(42, 99), "forty-two");
+/// assert_eq!(spell_or_digits(100, 99), "100");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn spell_or_digits(n: u128, max_spelled: u128) -> String {
+    if n <= max_spelled {
+        return u128_to_words(n);
+    }
+    return n.to_string();
+}
+
+/// Spells a word letter by letter (***f, o, r, t, y***).
+///
+/// # Arguments
+/// - `word`: `&str` of the word to be spelled.
+///
+/// # Returns
+/// A [`String`] with the (lowercase) ASCII letters and digits of `word` separated by commas.
+/// <br> Hyphens are read as ***hyphen***; all other characters are skipped.
+///
+/// # Example
+/// ```
+/// use num2en::spell_letters;
+///
+/// assert_eq!(spell_letters("forty"), "f, o, r, t, y");
+/// assert_eq!(spell_letters("Twenty-one"), "t, w, e, n, t, y, hyphen, o, n, e");
+/// ```
+pub fn spell_letters(word: &str) -> String {
+    word.chars()
+        .filter_map(|c| match c {
+            '-' => Some("hyphen".to_string()),
+            _ if c.is_ascii_alphanumeric() => Some(c.to_ascii_lowercase().to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Converts `n` to words, followed by each of the words spelled letter by letter.
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number to be converted.
+///
+/// # Returns
+/// A [`String`] in the `<cardinal>: <letters>` format, where the letters of each word are
+/// spelled by [spell_letters] and the words are separated by semicolons.
+///
+/// # Example
+/// ```
+/// use num2en::spell_number_letters;
+///
+/// assert_eq!(spell_number_letters(40), "forty: f, o, r, t, y");
+/// assert_eq!(spell_number_letters(102), "one hundred two: o, n, e; h, u, n, d, r, e, d; t, w, o");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn spell_number_letters(n: u128) -> String {
+    let words = u128_to_words(n);
+    let letters = words.split(' ').map(spell_letters).collect::<Vec<_>>().join("; ");
+    return words + ": " + &letters;
+}
+
+/// Converts `n` to words preceded by a label (e.g. ***option three***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u128`) that represents the number of the choice.
+/// - `label`: The word placed before the spelled number.
+///
+/// # Returns
+/// A [`String`] in the `<label> <cardinal>` format.
+///
+/// # Example
+/// ```
+/// use num2en::option_words;
+///
+/// assert_eq!(option_words(3, "option"), "option three");
+/// assert_eq!(option_words(12, "track"), "track twelve");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn option_words(n: u128, label: &str) -> String {
+    return label.to_string() + " " + &u128_to_words(n);
+}
+
+/// Converts a list of menu items to a spoken menu, numbering the items from one
+/// (***press one for ..., press two for ...***).
+///
+/// # Arguments
+/// - `items`: Descriptions of the menu items, in order.
+///
+/// # Returns
+/// A [`String`] with one `press <cardinal> for <item>` entry per item, separated by commas.
+///
+/// # Example
+/// ```
+/// use num2en::menu;
+///
+/// assert_eq!(menu(&["sales", "support"]), "press one for sales, press two for support");
+/// assert_eq!(menu(&[]), "");
+/// ```
+///
+/// # Notes
+/// - This function uses [option_words] behind the curtains.
+pub fn menu(items: &[&str]) -> String {
+    items.iter()
+        .enumerate()
+        .map(|(i, item)| option_words(i as u128 + 1, "press") + " for " + item)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Narrates a sequence of values, labelling each with its position and optionally ending with
+/// their total (***first: five; second: negative three; total: two***).
+///
+/// # Arguments
+/// - `values`: The values to be narrated, in order.
+/// - `separator`: The text placed between the entries (e.g. `"; "`).
+/// - `include_total`: Whether a final `total: <cardinal>` entry is added.
+///
+/// # Returns
+/// A [`String`] with one `<ordinal>: <cardinal>` entry per value, joined by `separator`.
+///
+/// # Example
+/// ```
+/// use num2en::sequence_narration;
+///
+/// assert_eq!(
+///     sequence_narration(&[5, -3, 10], "; ", true),
+///     "first: five; second: negative three; third: ten; total: twelve"
+/// );
+/// assert_eq!(sequence_narration(&[7, 8], ", ", false), "first: seven, second: eight");
+/// ```
+///
+/// # Notes
+/// - The total is summed as an `i128`, so it cannot overflow even when the sum exceeds `i64`.
+#[allow(clippy::needless_return)]
+pub fn sequence_narration(values: &[i64], separator: &str, include_total: bool) -> String {
+    let mut entries: Vec<String> = values.iter()
+        .enumerate()
+        .map(|(i, &value)| u128_to_ord_words(i as u128 + 1) + ": " + &i64_to_words(value))
+        .collect();
+
+    if include_total {
+        let total = values.iter()
+            .try_fold(0i128, |sum, &value| sum.checked_add(value as i128))
+            .expect("a sum of i64 values always fits in i128");
+        entries.push("total: ".to_string() + &i128_to_words(total));
+    }
+
+    return entries.join(separator);
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents the units a [`Duration`](core::time::Duration) can be broken into when calling
+/// [duration_to_words_compact_with].
+pub enum DurationUnit {
+    /// 86 400 seconds
+    Days,
+    /// 3600 seconds
+    Hours,
+    /// 60 seconds
+    Minutes,
+    /// The smallest unit; fractions of a second are never spelled.
+    Seconds,
+}
+
+impl DurationUnit {
+    fn seconds(self) -> u64 {
+        match self {
+            DurationUnit::Days => 86_400,
+            DurationUnit::Hours => 3600,
+            DurationUnit::Minutes => 60,
+            DurationUnit::Seconds => 1,
+        }
+    }
+
+    fn names(self) -> (&'static str, &'static str) {
+        match self {
+            DurationUnit::Days => ("day", "days"),
+            DurationUnit::Hours => ("hour", "hours"),
+            DurationUnit::Minutes => ("minute", "minutes"),
+            DurationUnit::Seconds => ("second", "seconds"),
+        }
+    }
+}
+
+/// Converts a [`Duration`](core::time::Duration) to its compact spoken form, using all units from
+/// days down to seconds (***two hours thirty minutes***).
+///
+/// # Arguments
+/// - `duration`: The [`Duration`](core::time::Duration) to be converted.
+///
+/// # Returns
+/// A [`String`] containing the spelled non-zero components separated by spaces, without commas or ***and***.
+///
+/// # Examples
+/// ```
+/// use num2en::duration_to_words_compact;
+/// use std::time::Duration;
+///
+/// let duration = Duration::from_secs(2 * 3600 + 30 * 60);
+/// assert_eq!(duration_to_words_compact(duration), "two hours thirty minutes");
+///
+/// let duration = Duration::from_secs(86_400 + 1);
+/// assert_eq!(duration_to_words_compact(duration), "one day one second");
+///
+/// let duration = Duration::from_millis(500);
+/// assert_eq!(duration_to_words_compact(duration), "zero seconds");
+/// ```
+///
+/// # Notes
+/// - Fractions of a second are truncated.
+/// - This function uses [duration_to_words_compact_with] behind the curtains.
+pub fn duration_to_words_compact(duration: core::time::Duration) -> String {
+    duration_to_words_compact_with(duration, DurationUnit::Days, DurationUnit::Seconds)
+}
+
+/// Converts a [`Duration`](core::time::Duration) to its compact spoken form, using only the units
+/// between `largest` and `smallest` (both inclusive).
+///
+/// # Arguments
+/// - `duration`: The [`Duration`](core::time::Duration) to be converted.
+/// - `largest`: The largest [`DurationUnit`] to be used. Anything larger is expressed in this unit.
+/// - `smallest`: The smallest [`DurationUnit`] to be used. Anything smaller is truncated.
+///
+/// # Returns
+/// A [`String`] containing the spelled non-zero components separated by spaces, without commas or ***and***.
+/// <br> If all components are zero, the result is ***zero*** followed by the plural of `smallest`.
+///
+/// # Examples
+/// ```
+/// use num2en::{duration_to_words_compact_with, DurationUnit};
+/// use std::time::Duration;
+///
+/// let duration = Duration::from_secs(2 * 86_400 + 3 * 3600 + 59);
+///
+/// let words = duration_to_words_compact_with(duration, DurationUnit::Hours, DurationUnit::Minutes);
+/// assert_eq!(words, "fifty-one hours");
+///
+/// let words = duration_to_words_compact_with(duration, DurationUnit::Minutes, DurationUnit::Seconds);
+/// assert_eq!(words, "three thousand sixty minutes fifty-nine seconds");
+/// ```
+///
+/// # Notes
+/// - If `largest` is a smaller unit than `smallest`, only `smallest` is used.
+#[allow(clippy::needless_return)]
+pub fn duration_to_words_compact_with(
+    duration: core::time::Duration,
+    largest: DurationUnit,
+    smallest: DurationUnit,
+) -> String {
+    const UNITS: [DurationUnit; 4] = [
+        DurationUnit::Days, DurationUnit::Hours, DurationUnit::Minutes, DurationUnit::Seconds,
+    ];
+
+    let largest_seconds = largest.seconds().max(smallest.seconds());
+    let mut remaining = duration.as_secs();
+
+    let mut words = Vec::<String>::new();
+    for unit in UNITS.iter() {
+        if unit.seconds() > largest_seconds || unit.seconds() < smallest.seconds() {
+            continue;
+        }
+        let count = remaining / unit.seconds();
+        remaining %= unit.seconds();
+        if count != 0 {
+            let (singular, plural) = unit.names();
+            words.push(count_to_words(count as u128, singular, plural));
+        }
+    }
+
+    if words.is_empty() {
+        let (singular, plural) = smallest.names();
+        return count_to_words(0, singular, plural);
+    }
+
+    return words.join(" ");
+}
+
+/// Converts a number of seconds to its compact spoken form in hours, minutes and seconds
+/// (***one hour one minute one second***).
+///
+/// # Arguments
+/// - `total`: An unsigned integer (`u64`) that represents the number of seconds.
+///
+/// # Returns
+/// A [`String`] containing the spelled non-zero components separated by spaces.
+/// <br> Zero seconds are read as ***zero seconds***.
+///
+/// # Examples
+/// ```
+/// use num2en::seconds_to_duration_words;
+///
+/// assert_eq!(seconds_to_duration_words(3661), "one hour one minute one second");
+/// assert_eq!(seconds_to_duration_words(90_000), "twenty-five hours");
+/// assert_eq!(seconds_to_duration_words(0), "zero seconds");
+/// ```
+///
+/// # Notes
+/// - Days are not used; to count them too, use [duration_to_words_compact].
+/// - This function uses [duration_to_words_compact_with] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn seconds_to_duration_words(total: u64) -> String {
+    let duration = core::time::Duration::from_secs(total);
+    return duration_to_words_compact_with(duration, DurationUnit::Hours, DurationUnit::Seconds);
+}
+
+
+/// Converts an offset in seconds relative to now to words (***two minutes ago***, ***in three hours***).
+///
+/// # Arguments
+/// - `secs`: A signed integer (`i64`) of seconds. Negative values lie in the past and positive values in the future.
+///
+/// # Returns
+/// A [`String`] containing the offset counted in the largest [`DurationUnit`] that fits at least once,
+/// framed as ***... ago*** or ***in ...***. An offset of zero is read as ***now***.
+///
+/// # Examples
+/// ```
+/// use num2en::relative_time_words;
+///
+/// assert_eq!(relative_time_words(-120), "two minutes ago");
+/// assert_eq!(relative_time_words(3 * 3600 + 59), "in three hours");
+/// assert_eq!(relative_time_words(1), "in one second");
+/// assert_eq!(relative_time_words(0), "now");
+/// ```
+///
+/// # Notes
+/// - The count is rounded down, so 119 seconds are ***one minute***.
+#[allow(clippy::needless_return)]
+pub fn relative_time_words(secs: i64) -> String {
+    const UNITS: [DurationUnit; 4] = [
+        DurationUnit::Days, DurationUnit::Hours, DurationUnit::Minutes, DurationUnit::Seconds,
+    ];
+
+    if secs == 0 {
+        return "now".to_string();
+    }
+
+    let magnitude = secs.unsigned_abs();
+    let unit = UNITS.iter().find(|unit| magnitude >= unit.seconds()).unwrap();
+    let (singular, plural) = unit.names();
+    let words = count_to_words((magnitude / unit.seconds()) as u128, singular, plural);
+
+    if secs < 0 {
+        return words + " ago";
+    }
+    return "in ".to_string() + &words;
+}
+
+
+#[derive(Debug, PartialEq)]
+/// Represents the possible error that can occur when calling [bearing_to_words] or [bearing_to_words_with].
+pub enum BearingConversionError {
+    /// Indicates that the bearing is 360 degrees or more.
+    OutOfRange,
+}
+
+impl BearingConversionError {
+    /// Returns a short human-readable description of the error.
+    pub fn message(&self) -> &'static str {
+        match self {
+            BearingConversionError::OutOfRange => "bearing must be between 0 and 359 degrees",
+        }
+    }
+}
+
+impl core::fmt::Display for BearingConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BearingConversionError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents the resolution of the compass used by [bearing_to_words_with].
+pub enum CompassPoints {
+    /// Cardinal and intercardinal directions (***north***, ***northeast***, ...)
+    Eight,
+    /// Cardinal, intercardinal and secondary intercardinal directions (***north-northeast***, ...)
+    Sixteen,
+}
+
+/// Converts a compass bearing to words with the nearest of the eight principal directions
+/// (***forty-five degrees, northeast***).
+///
+/// # Example
+/// ```
+/// use num2en::bearing_to_words;
+/// # use num2en::BearingConversionError;
+///
+/// assert_eq!(bearing_to_words(45), Ok("forty-five degrees, northeast".to_string()));
+/// assert_eq!(bearing_to_words(0), Ok("due north".to_string()));
+/// assert_eq!(bearing_to_words(360), Err(BearingConversionError::OutOfRange));
+/// ```
+///
+/// # Notes
+/// - This function uses [bearing_to_words_with] with [CompassPoints::Eight] behind the curtains.
+pub fn bearing_to_words(degrees: u16) -> Result<String, BearingConversionError> {
+    bearing_to_words_with(degrees, CompassPoints::Eight)
+}
+
+/// Converts a compass bearing to words with the nearest direction on a compass of the chosen resolution.
+///
+/// # Arguments
+/// - `degrees`: An unsigned integer (`u16`) between 0 and 359 that represents the bearing.
+/// - `points`: The [`CompassPoints`] resolution of the compass.
+///
+/// # Returns
+/// [`Result`]`<`[`String`]`, `[`BearingConversionError`]`>`
+///
+/// The string contains the spelled degrees and the nearest direction, separated by a comma.
+/// <br> Bearings of exactly 0, 90, 180 and 270 degrees are read as ***due north***, ***due east***,
+/// ***due south*** and ***due west***.
+///
+/// # Examples
+/// ```
+/// use num2en::{bearing_to_words_with, CompassPoints};
+///
+/// let words = bearing_to_words_with(20, CompassPoints::Sixteen);
+/// assert_eq!(words, Ok("twenty degrees, north-northeast".to_string()));
+///
+/// let words = bearing_to_words_with(20, CompassPoints::Eight);
+/// assert_eq!(words, Ok("twenty degrees, north".to_string()));
+///
+/// let words = bearing_to_words_with(270, CompassPoints::Sixteen);
+/// assert_eq!(words, Ok("due west".to_string()));
+/// ```
+#[allow(clippy::needless_return)]
+pub fn bearing_to_words_with(degrees: u16, points: CompassPoints) -> Result<String, BearingConversionError> {
+    const DIRECTIONS: [&str; 16] = [
+        "north", "north-northeast", "northeast", "east-northeast",
+        "east", "east-southeast", "southeast", "south-southeast",
+        "south", "south-southwest", "southwest", "west-southwest",
+        "west", "west-northwest", "northwest", "north-northwest",
+    ];
+
+    if degrees >= 360 {
+        return Err(BearingConversionError::OutOfRange);
+    }
+
+    if degrees % 90 == 0 {
+        return Ok("due ".to_string() + DIRECTIONS[degrees as usize / 90 * 4]);
+    }
+
+    // sectors are 45 or 22.5 degrees wide, so compute in quarter degrees
+    let direction = match points {
+        CompassPoints::Eight => (degrees as usize * 4 + 90) / 180 % 8 * 2,
+        CompassPoints::Sixteen => (degrees as usize * 4 + 45) / 90 % 16,
+    };
+
+    return Ok(count_to_words(degrees as u128, "degree", "degrees") + ", " + DIRECTIONS[direction]);
+}
+
+
+#[derive(Debug, PartialEq)]
+/// Represents the possible error that can occur when calling [day_ordinal_words], [day_ordinal_suffix]
+/// or [date_ordinal].
+pub enum DayConversionError {
+    /// Indicates that the day is not between 1 and 31.
+    OutOfRange,
+}
+
+impl DayConversionError {
+    /// Returns a short human-readable description of the error.
+    pub fn message(&self) -> &'static str {
+        match self {
+            DayConversionError::OutOfRange => "day of the month must be between 1 and 31",
+        }
+    }
+}
+
+impl core::fmt::Display for DayConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DayConversionError {}
+
+/// Converts a day of the month to its **ordinal** number representation in words (***third***).
+///
+/// # Example
+/// ```
+/// use num2en::day_ordinal_words;
+/// # use num2en::DayConversionError;
+///
+/// assert_eq!(day_ordinal_words(3), Ok("third".to_string()));
+/// assert_eq!(day_ordinal_words(31), Ok("thirty-first".to_string()));
+/// assert_eq!(day_ordinal_words(32), Err(DayConversionError::OutOfRange));
+/// ```
+#[allow(clippy::needless_return)]
+pub fn day_ordinal_words(day: u8) -> Result<String, DayConversionError> {
+    if !(1..=31).contains(&day) {
+        return Err(DayConversionError::OutOfRange);
+    }
+    return Ok(u8_to_ord_words(day));
+}
+
+/// Converts a day of the month to its **ordinal** number representation in words, preceded by the
+/// definite article (***the first***), as used when reading dates aloud.
+///
+/// # Example
+/// ```
+/// use num2en::u8_to_day_ordinal;
+/// # use num2en::DayConversionError;
+///
+/// assert_eq!(u8_to_day_ordinal(1), Ok("the first".to_string()));
+/// assert_eq!(u8_to_day_ordinal(22), Ok("the twenty-second".to_string()));
+/// assert_eq!(u8_to_day_ordinal(0), Err(DayConversionError::OutOfRange));
+/// ```
+///
+/// # Notes
+/// - This function uses [day_ordinal_words] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn u8_to_day_ordinal(day: u8) -> Result<String, DayConversionError> {
+    return Ok("the ".to_string() + &day_ordinal_words(day)?);
+}
+
+/// Converts a day of the month to digits followed by the ordinal suffix (***3rd***).
+///
+/// # Example
+/// ```
+/// use num2en::day_ordinal_suffix;
+/// # use num2en::DayConversionError;
+///
+/// assert_eq!(day_ordinal_suffix(3), Ok("3rd".to_string()));
+/// assert_eq!(day_ordinal_suffix(11), Ok("11th".to_string()));
+/// assert_eq!(day_ordinal_suffix(0), Err(DayConversionError::OutOfRange));
+/// ```
+#[allow(clippy::needless_return)]
+pub fn day_ordinal_suffix(day: u8) -> Result<String, DayConversionError> {
+    if !(1..=31).contains(&day) {
+        return Err(DayConversionError::OutOfRange);
+    }
+    return Ok(day.to_string() + ord_suffix(day as u128));
+}
+
+/// Converts a month name and a day of the month to a date in words (***June third***).
+///
+/// # Example
+/// ```
+/// use num2en::date_ordinal;
+///
+/// assert_eq!(date_ordinal("June", 3), Ok("June third".to_string()));
+/// ```
+///
+/// # Notes
+/// - `month` is used as is, without any validation.
+/// - This function uses [day_ordinal_words] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn date_ordinal(month: &str, day: u8) -> Result<String, DayConversionError> {
+    return Ok(month.to_string() + " " + &day_ordinal_words(day)?);
+}
+
+/// Capitalizes the first letter of number words, for use at the start of a sentence or in labels.
+///
+/// # Arguments
+/// - `words`: `&str` of words, as returned by any of the conversion functions.
+///
+/// # Returns
+/// A [`String`] with the first alphabetic character uppercased and everything else left untouched.
+///
+/// # Example
+/// ```
+/// use num2en::{capitalize_first, i32_to_words, u128_to_words};
+///
+/// assert_eq!(capitalize_first(&u128_to_words(142)), "One hundred forty-two");
+/// assert_eq!(capitalize_first(&i32_to_words(-2)), "Negative two");
+/// assert_eq!(capitalize_first(&u128_to_words(0)), "Zero");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn capitalize_first(words: &str) -> String {
+    match words.char_indices().find(|(_, c)| c.is_alphabetic()) {
+        Some((i, c)) => {
+            let mut result = words[..i].to_string();
+            result.extend(c.to_uppercase());
+            result.push_str(&words[i + c.len_utf8()..]);
+            return result;
+        },
+        None => return words.to_string(),
+    }
+}
+
+/// Capitalizes the first letter of every word, including both halves of hyphenated compounds,
+/// for use in headings.
+///
+/// # Arguments
+/// - `words`: `&str` of words, as returned by any of the conversion functions.
+///
+/// # Returns
+/// A [`String`] with the first letter of every space- or hyphen-separated word uppercased.
+///
+/// # Example
+/// ```
+/// use num2en::{to_title_case, u128_to_ord_words, str_to_words};
+///
+/// assert_eq!(to_title_case(&u128_to_ord_words(21)), "Twenty-First");
+/// assert_eq!(to_title_case("one hundred forty-two"), "One Hundred Forty-Two");
+/// assert_eq!(to_title_case(&str_to_words("-1.5").unwrap()), "Negative One Point Five");
+/// ```
+#[allow(clippy::needless_return)]
+pub fn to_title_case(words: &str) -> String {
+    let mut result = String::with_capacity(words.len());
+    let mut capitalize = true;
+    for c in words.chars() {
+        if capitalize {
+            result.extend(c.to_uppercase());
+        }
+        else {
+            result.push(c);
+        }
+        capitalize = c == ' ' || c == '-';
+    }
+    return result;
+}
+
+#[derive(Debug, PartialEq)]
+/// Represents the possible error that can occur when calling [to_usd_words], [to_usd_words_with],
+/// [check_line] or [to_check_words].
+pub enum CurrencyConversionError {
+    /// Indicates that the number of cents is greater than 99.
+    InvalidCents,
+}
+
+impl CurrencyConversionError {
+    /// Returns a short human-readable description of the error.
+    pub fn message(&self) -> &'static str {
+        match self {
+            CurrencyConversionError::InvalidCents => "cents must be between 0 and 99",
+        }
+    }
+}
+
+impl core::fmt::Display for CurrencyConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CurrencyConversionError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents how an amount without cents is read by [to_usd_words_with].
+pub enum ZeroCents {
+    /// The cents are read anyway (***one dollar and zero cents***). This is the default.
+    Spell,
+    /// The cents are left out (***one dollar***).
+    Omit,
+}
+
+/// Converts an amount of US dollars and cents to words
+/// (***one thousand two hundred thirty-four dollars and fifty cents***).
+///
+/// # Arguments
+/// - `dollars`: An unsigned integer (`u128`) that represents the whole dollars.
+/// - `cents`: The cents, between 0 and 99.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(String)`: The dollars and the cents in words, joined by ***and***.
+/// - `Err(CurrencyConversionError)`: [CurrencyConversionError::InvalidCents] if `cents` is greater than 99.
+///
+/// # Example
+/// ```
+/// use num2en::to_usd_words;
+/// # use num2en::CurrencyConversionError;
+///
+/// let words = to_usd_words(1_234, 50);
+/// assert_eq!(words, Ok("one thousand two hundred thirty-four dollars and fifty cents".to_string()));
+///
+/// assert_eq!(to_usd_words(1, 0), Ok("one dollar and zero cents".to_string()));
+/// assert_eq!(to_usd_words(1, 100), Err(CurrencyConversionError::InvalidCents));
+/// ```
+///
+/// # Notes
+/// - This function uses [to_usd_words_with] with [ZeroCents::Spell] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn to_usd_words(dollars: u128, cents: u8) -> Result<String, CurrencyConversionError> {
+    return to_usd_words_with(dollars, cents, ZeroCents::Spell);
+}
+
+/// Same as [to_usd_words], except that `zero_cents` decides whether an amount without cents
+/// is followed by ***and zero cents***.
+///
+/// # Example
+/// ```
+/// use num2en::{to_usd_words_with, ZeroCents};
+///
+/// assert_eq!(to_usd_words_with(5, 0, ZeroCents::Omit), Ok("five dollars".to_string()));
+/// assert_eq!(to_usd_words_with(5, 1, ZeroCents::Omit), Ok("five dollars and one cent".to_string()));
+/// ```
+#[allow(clippy::needless_return)]
+pub fn to_usd_words_with(dollars: u128, cents: u8, zero_cents: ZeroCents) -> Result<String, CurrencyConversionError> {
+    if cents > 99 {
+        return Err(CurrencyConversionError::InvalidCents);
+    }
+
+    if cents == 0 && zero_cents == ZeroCents::Omit {
+        return Ok(count_to_words(dollars, Currency::USD.major_singular, Currency::USD.major_plural));
+    }
+    return Ok(currency_to_words(dollars, cents as u64, &Currency::USD));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Describes the names of the major and minor units of a currency, for use with [currency_to_words].
+pub struct Currency<'a> {
+    /// e.g. ***dollar***
+    pub major_singular: &'a str,
+    /// e.g. ***dollars***
+    pub major_plural: &'a str,
+    /// e.g. ***cent***
+    pub minor_singular: &'a str,
+    /// e.g. ***cents***
+    pub minor_plural: &'a str,
+}
+
+impl Currency<'static> {
+    /// US dollars and cents.
+    pub const USD: Currency<'static> = Currency {
+        major_singular: "dollar", major_plural: "dollars", minor_singular: "cent", minor_plural: "cents",
+    };
+    /// Euros and cents.
+    pub const EUR: Currency<'static> = Currency {
+        major_singular: "euro", major_plural: "euros", minor_singular: "cent", minor_plural: "cents",
+    };
+    /// Pounds sterling and pence.
+    pub const GBP: Currency<'static> = Currency {
+        major_singular: "pound", major_plural: "pounds", minor_singular: "penny", minor_plural: "pence",
+    };
+}
+
+/// Converts an amount of money to words, using the unit names of `currency`
+/// (***two euros and ninety-nine cents***).
+///
+/// # Arguments
+/// - `major`: The amount in the major unit (e.g. euros).
+/// - `minor`: The amount in the minor unit (e.g. cents).
+/// - `currency`: The names of the units.
+///
+/// # Returns
+/// A [`String`] containing both amounts in words with their unit names, joined by ***and***.
+/// <br> The singular names are used only for amounts of exactly one.
+///
+/// # Example
+/// ```
+/// use num2en::{currency_to_words, Currency};
+///
+/// assert_eq!(currency_to_words(2, 99, &Currency::EUR), "two euros and ninety-nine cents");
+/// assert_eq!(currency_to_words(1, 1, &Currency::GBP), "one pound and one penny");
+///
+/// let yen = Currency { major_singular: "yen", major_plural: "yen", minor_singular: "sen", minor_plural: "sen" };
+/// assert_eq!(currency_to_words(500, 0, &yen), "five hundred yen and zero sen");
+/// ```
+///
+/// # Notes
+/// - `minor` is not checked against the size of the major unit.
+#[allow(clippy::needless_return)]
+pub fn currency_to_words(major: u128, minor: u64, currency: &Currency) -> String {
+    return join_remainder(
+        count_to_words(major, currency.major_singular, currency.major_plural),
+        &count_to_words(minor as u128, currency.minor_singular, currency.minor_plural),
+    );
+}
+
+/// Converts a decimal string amount of money to words, using the unit names of `currency`
+/// (***one thousand two hundred thirty-four dollars and fifty cents***).
+///
+/// # Arguments
+/// - `string`: A string slice (`&str`) that represents a non-negative amount with at most two
+///   fractional digits (e.g. `"1234.5"`).
+/// - `currency`: The names of the units.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(String)`: The amount in words, with a single ***and*** between the major and the minor
+///   amount.
+/// - `Err(StrConversionError)`: If the string is not a valid amount or the major amount is too large.
+///
+/// # Example
+/// ```
+/// use num2en::{str_to_currency_words, Currency};
+/// # use num2en::StrConversionError;
+///
+/// let words = str_to_currency_words("1234.5", &Currency::USD);
+/// assert_eq!(words, Ok("one thousand two hundred thirty-four dollars and fifty cents".to_string()));
+///
+/// assert_eq!(str_to_currency_words("1", &Currency::EUR), Ok("one euro and zero cents".to_string()));
+/// assert_eq!(str_to_currency_words("1.999", &Currency::USD), Err(StrConversionError::InvalidString));
+/// assert_eq!(str_to_currency_words("-1", &Currency::USD), Err(StrConversionError::InvalidString));
+/// ```
+///
+/// # Notes
+/// - The ***and*** is only placed before the minor amount; unlike with
+///   [WordsOptions::british], the major amount itself is spelled without any.
+/// - Digit grouping is accepted like in [str_to_words].
+#[allow(clippy::needless_return)]
+pub fn str_to_currency_words(string: &str, currency: &Currency) -> Result<String, StrConversionError> {
+    let number = parse_number_str(string)?;
+    if number.negative || (number.integer_part.is_none() && number.fractional_part.is_none()) {
+        return Err(StrConversionError::InvalidString);
+    }
+
+    let fractional_part = number.fractional_part.as_deref().unwrap_or("");
+    if fractional_part.len() > 2 {
+        return Err(StrConversionError::InvalidString);
+    }
+    let mut minor = 0;
+    for (i, byte) in fractional_part.bytes().enumerate() {
+        minor += (byte - b'0') as u64 * if i == 0 { 10 } else { 1 };
+    }
+
+    return Ok(currency_to_words(number.integer_part.unwrap_or(0), minor, currency));
+}
+
+/// Joins `words` and `remainder` with a single ***and***.
+#[allow(clippy::needless_return)]
+fn join_remainder(mut words: String, remainder: &str) -> String {
+    words.push_str(" and ");
+    words.push_str(remainder);
+    return words;
+}
+
+/// Converts a dollar amount to the line written on a check, padded to a fixed width
+/// (***One Thousand Two Hundred Thirty-Four and 00/100\*\*\*\*\*\****).
+///
+/// # Arguments
+/// - `dollars`: An unsigned integer (`u128`) that represents the whole dollars.
+/// - `cents`: The cents, between 0 and 99.
+/// - `width`: The length (in characters) the line is padded to.
+/// - `fill`: The character the line is padded with.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(String)`: The dollars in title-cased words, ***and***, the cents as a fraction of one hundred,
+///   and as many `fill` characters as needed to reach `width`.
+///   <br> Lines that are already `width` characters or longer are not padded nor truncated.
+/// - `Err(CurrencyConversionError)`: [CurrencyConversionError::InvalidCents] if `cents` is greater than 99.
+///
+/// # Example
+/// ```
+/// use num2en::check_line;
+/// # use num2en::CurrencyConversionError;
+///
+/// let line = check_line(1_234, 0, 60, '*').unwrap();
+/// assert_eq!(line, "One Thousand Two Hundred Thirty-Four and 00/100*************");
+/// assert_eq!(line.len(), 60);
+///
+/// assert_eq!(check_line(5, 7, 0, '*'), Ok("Five and 07/100".to_string()));
+/// assert_eq!(check_line(5, 100, 0, '*'), Err(CurrencyConversionError::InvalidCents));
+/// ```
+#[allow(clippy::needless_return)]
+pub fn check_line(dollars: u128, cents: u8, width: usize, fill: char) -> Result<String, CurrencyConversionError> {
+    let mut line = check_amount(to_title_case(&u128_to_words(dollars)), cents, CheckZeroCents::Digits)?;
+    let len = line.chars().count();
+    if len < width {
+        line.extend(core::iter::repeat(fill).take(width - len));
+    }
+    return Ok(line);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Represents how zero cents are written by [to_check_words_with].
+pub enum CheckZeroCents {
+    /// As digits (***00/100***). This is the default.
+    Digits,
+    /// As ***no/100***.
+    No,
+}
+
+/// Converts a dollar amount to the check-writing style, with the cents as a fraction of one hundred
+/// (***one hundred twenty-three and 50/100 dollars***).
+///
+/// # Arguments
+/// - `dollars`: An unsigned integer (`u128`) that represents the whole dollars.
+/// - `cents`: The cents, between 0 and 99.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(String)`: The dollars in words, ***and***, the cents written with digits over ***100***,
+///   and ***dollars***.
+/// - `Err(CurrencyConversionError)`: [CurrencyConversionError::InvalidCents] if `cents` is greater than 99.
+///
+/// # Example
+/// ```
+/// use num2en::to_check_words;
+/// # use num2en::CurrencyConversionError;
+///
+/// assert_eq!(to_check_words(123, 50), Ok("one hundred twenty-three and 50/100 dollars".to_string()));
+/// assert_eq!(to_check_words(1, 0), Ok("one and 00/100 dollars".to_string()));
+/// assert_eq!(to_check_words(1, 100), Err(CurrencyConversionError::InvalidCents));
+/// ```
+///
+/// # Notes
+/// - This function uses [to_check_words_with] with [CheckZeroCents::Digits] behind the curtains.
+/// - [check_line] writes the same amount title-cased and without ***dollars***.
+#[allow(clippy::needless_return)]
+pub fn to_check_words(dollars: u128, cents: u8) -> Result<String, CurrencyConversionError> {
+    return to_check_words_with(dollars, cents, CheckZeroCents::Digits);
+}
+
+/// Same as [to_check_words], except that `zero_cents` determines how zero cents are written.
+///
+/// # Example
+/// ```
+/// use num2en::{to_check_words_with, CheckZeroCents};
+///
+/// assert_eq!(to_check_words_with(40, 0, CheckZeroCents::No), Ok("forty and no/100 dollars".to_string()));
+/// assert_eq!(to_check_words_with(40, 0, CheckZeroCents::Digits), Ok("forty and 00/100 dollars".to_string()));
+/// assert_eq!(to_check_words_with(40, 5, CheckZeroCents::No), Ok("forty and 05/100 dollars".to_string()));
+/// ```
+#[allow(clippy::needless_return)]
+pub fn to_check_words_with(dollars: u128, cents: u8, zero_cents: CheckZeroCents) -> Result<String, CurrencyConversionError> {
+    return Ok(check_amount(u128_to_words(dollars), cents, zero_cents)? + " dollars");
+}
+
+/// Joins `dollar_words` and the cents written over ***100*** with ***and***, as on a check.
+#[allow(clippy::needless_return)]
+fn check_amount(dollar_words: String, cents: u8, zero_cents: CheckZeroCents) -> Result<String, CurrencyConversionError> {
+    if cents > 99 {
+        return Err(CurrencyConversionError::InvalidCents);
+    }
+
+    let cents = match (cents, zero_cents) {
+        (0, CheckZeroCents::No) => "no".to_string(),
+        _ => format!("{:02}", cents),
+    };
+    return Ok(join_remainder(dollar_words, &(cents + "/100")));
+}
+
+#[derive(Debug, PartialEq)]
+/// Represents the possible errors that can occur when calling [words_to_u128] or [words_to_str].
+pub enum WordsParseError {
+    /// Indicates that the input contains a word that is not a number word, or a malformed
+    /// hyphenated compound (e.g. ***twenty-ten***).
+    InvalidWord,
+    /// Indicates that the number words are in an order that does not form a number
+    /// (e.g. ***five six***, ***thousand million***), or that the input is empty.
+    InvalidSequence,
+    /// Indicates that the number is too large to be represented (max is 2<sup>128</sup> - 1, or
+    /// outside the `i128` range for [words_to_i128]).
+    TooLarge,
+}
+
+impl WordsParseError {
+    /// Returns a short human-readable description of the error.
+    pub fn message(&self) -> &'static str {
+        match self {
+            WordsParseError::InvalidWord => "input contains a word that is not a number word",
+            WordsParseError::InvalidSequence => "number words are not in a valid order",
+            WordsParseError::TooLarge => "number is too large to convert (max is 2^128 - 1)",
+        }
+    }
+}
+
+impl core::fmt::Display for WordsParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WordsParseError {}
+
+/// A single (lowercase) number word, as recognized by [words_to_u128].
+#[derive(Clone, Copy, PartialEq)]
+enum NumberWord {
+    /// ***zero***
+    Zero,
+    /// ***one*** to ***ninety-nine***
+    Lt100(u8),
+    /// ***hundred***
+    Hundred,
+    /// ***thousand***, ***million***, ... (index into [PERIODS])
+    Period(usize),
+    /// ***and***
+    And,
+}
+
+#[allow(clippy::needless_return)]
+fn parse_number_word(word: &str) -> Result<NumberWord, WordsParseError> {
+    let lt20 = |word: &str| NUMS_SMALLER_THAN_20.iter().position(|&x| x == word).map(|i| (i + NUMS_SMALLER_THAN_20_OFFSET) as u8);
+    let tens = |word: &str| MULTIPLES_OF_10.iter().position(|&x| x == word).map(|i| ((i + MULTIPLES_OF_10_OFFSET) * 10) as u8);
+
+    if let Some((tens_word, ones_word)) = word.split_once('-') {
+        return match (tens(tens_word), lt20(ones_word)) {
+            (Some(tens), Some(ones)) if ones < 10 => Ok(NumberWord::Lt100(tens + ones)),
+            _ => Err(WordsParseError::InvalidWord),
+        };
+    }
+    if let Some(n) = lt20(word).or_else(|| tens(word)) {
+        return Ok(NumberWord::Lt100(n));
+    }
+    if let Some(idx) = PERIODS.iter().position(|&x| x == word) {
+        return Ok(NumberWord::Period(idx));
+    }
+    match word {
+        "zero" => return Ok(NumberWord::Zero),
+        "hundred" => return Ok(NumberWord::Hundred),
+        "and" => return Ok(NumberWord::And),
+        _ => return Err(WordsParseError::InvalidWord),
+    }
+}
+
+/// Parses a group below one thousand (***three hundred [and] forty-two***) at the start of `words`,
+/// returning its value and the number of words it takes.
+#[allow(clippy::needless_return)]
+fn parse_group_words(words: &[NumberWord]) -> Result<(u16, usize), WordsParseError> {
+    let first = match words.first() {
+        Some(NumberWord::Lt100(n)) => *n as u16,
+        _ => return Err(WordsParseError::InvalidSequence),
+    };
+    if words.get(1) != Some(&NumberWord::Hundred) {
+        return Ok((first, 1));
+    }
+    if first > 9 {
+        return Err(WordsParseError::InvalidSequence);
+    }
+
+    match words[2..] {
+        [NumberWord::Lt100(n), ..] | [NumberWord::And, NumberWord::Lt100(n), ..] => {
+            let len = if words[2] == NumberWord::And { 4 } else { 3 };
+            return Ok((first * 100 + n as u16, len));
+        },
+        [NumberWord::And, ..] => return Err(WordsParseError::InvalidSequence),
+        _ => return Ok((first * 100, 2)),
+    }
+}
+
+/// Joins the tens and the ones of compounds written without a hyphen (***twenty one***, see
+/// [WordsOptions::hyphenate]) into a single word.
+fn join_unhyphenated_compounds(words: &mut Vec<NumberWord>) {
+    let mut i = 1;
+    while i < words.len() {
+        match (&words[i - 1], &words[i]) {
+            (NumberWord::Lt100(tens), NumberWord::Lt100(ones)) if *tens >= 20 && tens % 10 == 0 && (1..10).contains(ones) => {
+                words[i - 1] = NumberWord::Lt100(tens + ones);
+                words.remove(i);
+            },
+            _ => i += 1,
+        }
+    }
+}
+
+/// Converts number words back to the number they represent (the inverse of [u128_to_words]).
+///
+/// # Arguments
+/// - `words`: `&str` of **cardinal** number words separated by whitespace, such as ***one hundred
+///   twenty-three*** or ***two thousand twelve***.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(u128)`: The number represented by `words`.
+/// - `Err(WordsParseError)`: An error indicating why `words` could not be parsed.
+///
+/// # Examples
+/// ```
+/// use num2en::{words_to_u128, u128_to_words, u128_to_words_british};
+/// # use num2en::WordsParseError;
+///
+/// assert_eq!(words_to_u128("one hundred twenty-three"), Ok(123));
+/// assert_eq!(words_to_u128("two thousand and twelve"), Ok(2012));
+/// assert_eq!(words_to_u128("twenty one thousand"), Ok(21_000));
+/// assert_eq!(words_to_u128(&u128_to_words(u128::MAX)), Ok(u128::MAX));
+/// assert_eq!(words_to_u128(&u128_to_words_british(101_101)), Ok(101_101));
+///
+/// assert_eq!(words_to_u128("one hundred potatoes"), Err(WordsParseError::InvalidWord));
+/// assert_eq!(words_to_u128("thousand one"), Err(WordsParseError::InvalidSequence));
+/// assert_eq!(words_to_u128("one thousand undecillion"), Err(WordsParseError::InvalidSequence));
+/// assert_eq!(words_to_u128("nine hundred undecillion"), Err(WordsParseError::TooLarge));
+/// ```
+///
+/// # Notes
+/// - Letter case is ignored.
+/// - An optional ***and*** is accepted after ***hundred*** and after a period name, like in
+///   British English, but not in other places.
+/// - Compounds below one hundred may be written with or without a hyphen (***twenty-one*** or
+///   ***twenty one***).
+/// - Every period (***thousand***, ***million***, ...) may appear at most once and only in
+///   decreasing order, and the value before ***hundred*** must be between one and nine.
+/// - Every step of the accumulation is checked, so a number just past `u128::MAX` returns
+///   [TooLarge](WordsParseError::TooLarge) even when a single period already overflows.
+#[allow(clippy::needless_return)]
+pub fn words_to_u128(words: &str) -> Result<u128, WordsParseError> {
+    let mut words = words.to_lowercase()
+        .split_whitespace()
+        .map(parse_number_word)
+        .collect::<Result<Vec<_>, _>>()?;
+    join_unhyphenated_compounds(&mut words);
+
+    if words == [NumberWord::Zero] {
+        return Ok(0);
+    }
+
+    let mut total: u128 = 0;
+    let mut last_period: Option<usize> = None;
+    let mut i = 0;
+    while i < words.len() {
+        if words[i] == NumberWord::And {
+            // allowed only right after a period name and before another group
+            if last_period.is_none() || words[i - 1] == NumberWord::And || i + 1 == words.len() {
+                return Err(WordsParseError::InvalidSequence);
+            }
+            i += 1;
+        }
+
+        let (group, len) = parse_group_words(&words[i..])?;
+        i += len;
+
+        match words.get(i) {
+            Some(NumberWord::Period(idx)) => {
+                if last_period.map_or(false, |last_idx| *idx >= last_idx) {
+                    return Err(WordsParseError::InvalidSequence);
+                }
+                let value = 1000u128.checked_pow(*idx as u32 + 1)
+                    .and_then(|scale| scale.checked_mul(group as u128))
+                    .and_then(|value| value.checked_add(total))
+                    .ok_or(WordsParseError::TooLarge)?;
+                total = value;
+                last_period = Some(*idx);
+                i += 1;
+            },
+            None => {
+                total = total.checked_add(group as u128).ok_or(WordsParseError::TooLarge)?;
+            },
+            Some(_) => return Err(WordsParseError::InvalidSequence),
+        }
+    }
+
+    if i == 0 {
+        return Err(WordsParseError::InvalidSequence);
+    }
+    return Ok(total);
+}
+
+/// Converts number words, optionally preceded by ***negative***, back to the signed number they
+/// represent (the inverse of [i128_to_words]).
+///
+/// # Arguments
+/// - `words`: `&str` of **cardinal** number words separated by whitespace, such as ***negative
+///   forty-two***.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(i128)`: The number represented by `words`.
+/// - `Err(WordsParseError)`: An error indicating why `words` could not be parsed.
+///   [TooLarge](WordsParseError::TooLarge) is returned if the number is outside the `i128` range.
+///
+/// # Example
+/// ```
+/// use num2en::{words_to_i128, i128_to_words};
+/// # use num2en::WordsParseError;
+///
+/// assert_eq!(words_to_i128("negative forty-two"), Ok(-42));
+/// assert_eq!(words_to_i128("seven"), Ok(7));
+/// assert_eq!(words_to_i128(&i128_to_words(i128::MIN)), Ok(i128::MIN));
+/// assert_eq!(words_to_i128("negative"), Err(WordsParseError::InvalidSequence));
+/// ```
+///
+/// # Notes
+/// - Everything after ***negative*** is parsed by [words_to_u128], so the same rules apply to it.
+/// - `i128::MIN`, whose magnitude does not fit in an `i128`, is parsed without overflowing.
+#[allow(clippy::needless_return)]
+pub fn words_to_i128(words: &str) -> Result<i128, WordsParseError> {
+    let words = words.trim_start();
+    let (negative, magnitude_words) = match words.split_once(char::is_whitespace) {
+        Some((first, rest)) if first.eq_ignore_ascii_case("negative") => (true, rest),
+        _ if words.eq_ignore_ascii_case("negative") => return Err(WordsParseError::InvalidSequence),
+        _ => (false, words),
+    };
+
+    let magnitude = words_to_u128(magnitude_words)?;
+    if negative && magnitude == i128::MIN.unsigned_abs() {
+        return Ok(i128::MIN);
+    }
+    let n = i128::try_from(magnitude).map_err(|_| WordsParseError::TooLarge)?;
+    return Ok(if negative { -n } else { n });
+}
+
+/// Converts **ordinal** number words back to the number they represent (the inverse of [u128_to_ord_words]).
+///
+/// # Arguments
+/// - `words`: `&str` of number words separated by whitespace, where the last word is an ordinal,
+///   such as ***twenty-first*** or ***one hundred eightieth***.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(u128)`: The number represented by `words`.
+/// - `Err(WordsParseError)`: An error indicating why `words` could not be parsed.
+///   [InvalidWord](WordsParseError::InvalidWord) is returned if the last word is not an ordinal.
+///
+/// # Example
+/// ```
+/// use num2en::words_to_ord_u128;
+/// # use num2en::WordsParseError;
+///
+/// assert_eq!(words_to_ord_u128("twenty-first"), Ok(21));
+/// assert_eq!(words_to_ord_u128("one hundred eightieth"), Ok(180));
+/// assert_eq!(words_to_ord_u128("one thousandth"), Ok(1000));
+/// assert_eq!(words_to_ord_u128("twenty-one"), Err(WordsParseError::InvalidWord));
+/// ```
+///
+/// # Notes
+/// - Everything before the last word is parsed by [words_to_u128], so the same rules apply to it.
+#[allow(clippy::needless_return)]
+pub fn words_to_ord_u128(words: &str) -> Result<u128, WordsParseError> {
+    let lowercase = words.to_lowercase();
+    let mut words = lowercase.split_whitespace().collect::<Vec<_>>();
+    let ord_word = words.pop().ok_or(WordsParseError::InvalidSequence)?;
+
+    let (prefix, last_word) = match ord_word.rfind('-') {
+        Some(hyphen_index) => ord_word.split_at(hyphen_index + 1),
+        None => ("", ord_word),
+    };
+    let cardinal_word = match ORD_NUMS_EXCEPTIONS.iter().find(|x| x.1 == last_word) {
+        Some((cardinal, _)) => cardinal.to_string(),
+        None => match (last_word.strip_suffix("ieth"), last_word.strip_suffix("th").filter(|stem| !stem.is_empty())) {
+            (Some(stem), _) => stem.to_string() + "y",
+            (None, Some(stem)) => stem.to_string(),
+            (None, None) => return Err(WordsParseError::InvalidWord),
+        },
+    };
+    let cardinal_word = prefix.to_string() + &cardinal_word;
+    // rejects made-up forms like "twentyth" or "oneth"
+    if ordinalize_word(&cardinal_word) != ord_word {
+        return Err(WordsParseError::InvalidWord);
+    }
+
+    words.push(&cardinal_word);
+    return words_to_u128(&words.join(" "));
+}
+
+/// Converts number words with an optional decimal point back to a string of the number they
+/// represent (the inverse of [str_to_words]).
+///
+/// # Arguments
+/// - `words`: `&str` of number words, optionally starting with ***negative*** and optionally
+///   followed by ***point*** and individually spelled digits (***zero*** to ***nine***).
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(String)`: The number in the format accepted by [str_to_words], without leading zeros.
+/// - `Err(WordsParseError)`: An error indicating why `words` could not be parsed.
+///
+/// # Examples
+/// ```
+/// use num2en::words_to_str;
+/// # use num2en::WordsParseError;
+///
+/// assert_eq!(words_to_str("one hundred twenty-three point four five six"), Ok("123.456".to_string()));
+/// assert_eq!(words_to_str("point zero zero four two"), Ok(".0042".to_string()));
+/// assert_eq!(words_to_str("negative seven"), Ok("-7".to_string()));
+/// assert_eq!(words_to_str("one point twenty"), Err(WordsParseError::InvalidSequence));
+/// ```
+///
+/// # Notes
+/// - The integer part is parsed by [words_to_u128], so the same rules apply to it.
+#[allow(clippy::needless_return)]
+pub fn words_to_str(words: &str) -> Result<String, WordsParseError> {
+    const DIGITS: [&str; 10] = ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+    let lowercase = words.to_lowercase();
+    let mut words = lowercase.split_whitespace().collect::<Vec<_>>();
+
+    let mut number = String::new();
+    if words.first() == Some(&"negative") {
+        words.remove(0);
+        number.push('-');
+    }
+
+    let (integer_words, fractional_words) = match words.iter().position(|&word| word == "point") {
+        Some(i) => (&words[..i], Some(&words[i + 1..])),
+        None => (&words[..], None),
+    };
+
+    if integer_words.contains(&"negative") {
+        return Err(WordsParseError::InvalidSequence);
+    }
+    if !integer_words.is_empty() {
+        number += &words_to_u128(&integer_words.join(" "))?.to_string();
+    }
+    else if fractional_words.map_or(true, |words| words.is_empty()) {
+        return Err(WordsParseError::InvalidSequence);
+    }
+
+    if let Some(fractional_words) = fractional_words {
+        number.push('.');
+        for &word in fractional_words {
+            match DIGITS.iter().position(|&digit| digit == word) {
+                Some(digit) => number.push((b'0' + digit as u8) as char),
+                None if word == "point" || word == "negative" => return Err(WordsParseError::InvalidSequence),
+                None => {
+                    // a number word other than a digit is misplaced, anything else is invalid
+                    parse_number_word(word)?;
+                    return Err(WordsParseError::InvalidSequence);
+                },
+            }
+        }
+    }
+
+    return Ok(number);
+}
+
+/// Converts number words with an optional decimal point back to the `f64` value they represent
+/// (the inverse of [f64_to_words]).
+///
+/// # Arguments
+/// - `words`: `&str` of number words, in the format accepted by [words_to_str].
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(f64)`: The closest `f64` to the number the words represent.
+/// - `Err(WordsParseError)`: An error indicating why `words` could not be parsed.
+///
+/// # Example
+/// ```
+/// use num2en::words_to_f64;
+/// # use num2en::WordsParseError;
+///
+/// assert_eq!(words_to_f64("forty-two point four two"), Ok(42.42));
+/// assert_eq!(words_to_f64("negative point five"), Ok(-0.5));
+/// assert_eq!(words_to_f64("forty-two point"), Ok(42.0));
+/// assert_eq!(words_to_f64("point point"), Err(WordsParseError::InvalidSequence));
+/// ```
+///
+/// # Notes
+/// - Digits beyond the precision of `f64` are rounded away.
+/// - This function uses [words_to_str] behind the curtains.
+#[allow(clippy::needless_return)]
+pub fn words_to_f64(words: &str) -> Result<f64, WordsParseError> {
+    let number = words_to_str(words)?;
+    return number.parse::<f64>().map_err(|_| WordsParseError::InvalidSequence);
+}
 
 #[cfg(test)]
 mod tests;
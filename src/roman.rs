@@ -0,0 +1,89 @@
+//! Conversion of integers to Roman numerals.
+
+use alloc::string::String;
+
+#[derive(Debug, PartialEq)]
+/// Represents the possible errors that can occur when calling [u16_to_roman] or [u16_to_roman_lowercase].
+pub enum RomanError {
+    /// Indicates that the number is zero, which has no Roman numeral.
+    Zero,
+    /// Indicates that the number is greater than 3999, the largest number written without overlines.
+    TooLarge,
+}
+
+impl RomanError {
+    /// Returns a short human-readable description of the error.
+    pub fn message(&self) -> &'static str {
+        match self {
+            RomanError::Zero => "zero has no Roman numeral",
+            RomanError::TooLarge => "number is too large for Roman numerals (max is 3999)",
+        }
+    }
+}
+
+impl core::fmt::Display for RomanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RomanError {}
+
+/// values of the Roman numerals, including the subtractive pairs
+const NUMERALS: [(u16, &str); 13] = [
+    (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"), (90, "XC"),
+    (50, "L"), (40, "XL"), (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I"),
+];
+
+/// Converts a `u16` value to its Roman numeral (***MCMLXXXIV***).
+///
+/// # Arguments
+/// - `n`: An unsigned integer (`u16`) between 1 and 3999.
+///
+/// # Returns
+/// A `Result` containing:
+/// - `Ok(String)`: The Roman numeral in uppercase letters.
+/// - `Err(RomanError)`: [RomanError::Zero] for 0, or [RomanError::TooLarge] for numbers above 3999.
+///
+/// # Example
+/// ```
+/// use num2en::u16_to_roman;
+/// # use num2en::RomanError;
+///
+/// assert_eq!(u16_to_roman(1984), Ok("MCMLXXXIV".to_string()));
+/// assert_eq!(u16_to_roman(0), Err(RomanError::Zero));
+/// assert_eq!(u16_to_roman(4000), Err(RomanError::TooLarge));
+/// ```
+#[allow(clippy::needless_return)]
+pub fn u16_to_roman(n: u16) -> Result<String, RomanError> {
+    if n == 0 {
+        return Err(RomanError::Zero);
+    }
+    if n > 3999 {
+        return Err(RomanError::TooLarge);
+    }
+
+    let mut roman = String::new();
+    let mut rest = n;
+    for (value, numeral) in NUMERALS.iter() {
+        while rest >= *value {
+            roman.push_str(numeral);
+            rest -= value;
+        }
+    }
+    return Ok(roman);
+}
+
+/// Same as [u16_to_roman], except that the numeral is in lowercase letters (***mcmlxxxiv***).
+///
+/// # Example
+/// ```
+/// use num2en::u16_to_roman_lowercase;
+///
+/// assert_eq!(u16_to_roman_lowercase(14), Ok("xiv".to_string()));
+/// ```
+#[allow(clippy::needless_return)]
+pub fn u16_to_roman_lowercase(n: u16) -> Result<String, RomanError> {
+    return u16_to_roman(n).map(|roman| roman.to_lowercase());
+}
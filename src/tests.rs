@@ -6,6 +6,7 @@ fn testdata_filepath(filename: &str) -> String {
     env!("CARGO_MANIFEST_DIR").to_string() + "/testdata/" + filename
 }
 
+#[allow(clippy::needless_return)]
 fn get_inputs_and_expected_outputs(testdata_filename: &str) -> Vec<(String, String)> {
     let lines = fs::read_to_string(testdata_filepath(testdata_filename))
         .expect("There should be a file in testdata")
@@ -233,6 +234,22 @@ fn nums_represented_by_str() {
         |x| str_to_words(&x));
 }
 
+#[test]
+fn signed_grouped_decimal_strs() {
+    let expected = "negative one million two hundred thirty-four thousand five hundred sixty-seven point eight nine";
+    assert_eq!(str_to_words("-1,234,567.89"), Ok(expected.to_string()));
+    assert_eq!(str_to_words("-1_234_567.89"), Ok(expected.to_string()));
+    assert_eq!(str_to_words("+1,234.50"), Ok("one thousand two hundred thirty-four point five zero".to_string()));
+    assert_eq!(str_to_words("-999,999."), Ok("negative nine hundred ninety-nine thousand nine hundred ninety-nine point".to_string()));
+
+    for invalid in [
+        "-1.234,5", "+1.234,5", "-1,234.567,8", "-1,234.5_6", "-1,23,456.7", "+1,2345.6",
+        "-,123.4", "-1,234,.5", "-1,234_567.8", "1,-234.5", "-1,234.5e,3",
+    ] {
+        assert_eq!(str_to_words(invalid), Err(StrConversionError::InvalidString), "input: '{}'", invalid);
+    }
+}
+
 #[test]
 fn nums_represented_by_f32() {
     test_result_func("f32_nums_ok.csv",
@@ -322,3 +339,1862 @@ fn ord_nums_represented_by_usize() {
         |o| o.to_string(),
         usize_to_ord_words);
 }
+
+#[test]
+fn counts_of_items() {
+    assert_eq!(count_to_words(0, "item", "items"), "zero items");
+    assert_eq!(count_to_words(1, "item", "items"), "one item");
+    assert_eq!(count_to_words(1_001, "item", "items"), "one thousand one items");
+    assert_eq!(count_or_none(0, "result", "results"), "no results");
+    assert_eq!(count_or_none(1, "result", "results"), "one result");
+    assert_eq!(count_or_none(3, "result", "results"), "three results");
+    assert_eq!(count_or_zero_word(0, "zero", "result", "results"), count_to_words(0, "result", "results"));
+}
+
+#[test]
+fn compact_durations() {
+    use std::time::Duration;
+
+    assert_eq!(duration_to_words_compact(Duration::from_secs(0)), "zero seconds");
+    assert_eq!(duration_to_words_compact(Duration::from_secs(1)), "one second");
+    assert_eq!(duration_to_words_compact(Duration::from_secs(3661)), "one hour one minute one second");
+    assert_eq!(duration_to_words_compact(Duration::from_secs(9000)), "two hours thirty minutes");
+    assert_eq!(duration_to_words_compact(Duration::from_secs(172_800)), "two days");
+
+    let duration = Duration::from_secs(90_061);
+    assert_eq!(duration_to_words_compact_with(duration, DurationUnit::Days, DurationUnit::Hours),
+        "one day one hour");
+    assert_eq!(duration_to_words_compact_with(duration, DurationUnit::Hours, DurationUnit::Seconds),
+        "twenty-five hours one minute one second");
+    assert_eq!(duration_to_words_compact_with(Duration::from_secs(59), DurationUnit::Hours, DurationUnit::Minutes),
+        "zero minutes");
+    assert_eq!(duration_to_words_compact_with(duration, DurationUnit::Seconds, DurationUnit::Minutes),
+        "one thousand five hundred one minutes");
+}
+
+#[test]
+fn nums_represented_by_str_split_into_parts() {
+    let inputs_and_expected_outputs = get_inputs_and_expected_outputs("str_nums_ok.csv");
+
+    for (input, expected_output) in inputs_and_expected_outputs {
+        let (integer_words, point_word, fractional_words) = str_to_words_parts(&input).unwrap();
+        assert!(point_word.is_empty() || point_word == "point");
+        let joined = [integer_words, point_word, fractional_words].iter()
+            .filter(|x| !x.is_empty())
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" ");
+        assert_eq!(expected_output, joined, "input: '{}'", input);
+    }
+
+    assert_eq!(str_to_words_parts(""), Ok(("".to_string(), "".to_string(), "".to_string())));
+    assert_eq!(str_to_words_parts("-7."), Ok(("negative seven".to_string(), "point".to_string(), "".to_string())));
+    assert_eq!(str_to_words_parts("1..2"), Err(StrConversionError::InvalidString));
+}
+
+#[test]
+fn fullwidth_digits() {
+    assert_eq!(normalize_fullwidth_digits("０１２３４５６７８９"), "0123456789");
+    assert_eq!(normalize_fullwidth_digits("a１b"), "a1b");
+
+    assert_eq!(str_digits_to_words_fullwidth("１２３"), Ok("one two three".to_string()));
+    assert_eq!(str_digits_to_words_fullwidth("1２3"), Ok("one two three".to_string()));
+    assert_eq!(str_digits_to_words_fullwidth("１x"), Err(DigitConversionError::InvalidCharacter));
+    assert_eq!(str_digits_to_words("１２３"), Err(DigitConversionError::InvalidCharacter));
+
+    assert_eq!(str_to_words_fullwidth("-１２.０５"), Ok("negative twelve point zero five".to_string()));
+    assert_eq!(str_to_words_fullwidth("１．５"), Err(StrConversionError::InvalidString));
+    assert_eq!(str_to_words("１２３"), Err(StrConversionError::InvalidString));
+}
+
+#[test]
+fn word_tokens() {
+    for (input, expected_output) in get_inputs_and_expected_outputs("u128_nums.csv") {
+        let n = input.parse().unwrap();
+        let tokens = u128_to_words_tokens(n);
+        assert_eq!(tokens.join(" "), expected_output);
+
+        let mut tokens_rev = u128_to_words_tokens_rev(n);
+        tokens_rev.reverse();
+        assert_eq!(tokens_rev, tokens);
+    }
+
+    assert_eq!(u128_to_words_tokens(0), vec!["zero"]);
+    assert_eq!(u128_to_words_tokens_rev(2_000_042), vec!["forty-two", "million", "two"]);
+}
+
+#[test]
+fn options_and_menus() {
+    assert_eq!(option_words(0, "option"), "option zero");
+    assert_eq!(option_words(21, "chapter"), "chapter twenty-one");
+
+    assert_eq!(menu(&[]), "");
+    assert_eq!(menu(&["billing"]), "press one for billing");
+    assert_eq!(menu(&["billing", "sales", "support"]),
+        "press one for billing, press two for sales, press three for support");
+}
+
+#[test]
+fn powers() {
+    assert_eq!(power_to_words(10, 1), "ten");
+    assert_eq!(power_to_words(10, 0), "ten to the zeroth");
+    assert_eq!(power_to_words(10, 2), "ten to the second");
+    assert_eq!(power_to_words(10, -1), "ten to the negative first");
+    assert_eq!(power_to_words(10, -12), "ten to the negative twelfth");
+    assert_eq!(power_to_words(-2, 5), "negative two to the fifth");
+    assert_eq!(power_to_words(7, -21), "seven to the negative twenty-first");
+    assert_eq!(power_to_words_with(10, -1, PowerStyle::Per), "per ten");
+    assert_eq!(power_to_words_with(10, -2, PowerStyle::Per), "per hundred");
+    assert_eq!(power_to_words_with(10, -6, PowerStyle::Per), "per million");
+    assert_eq!(power_to_words_with(2, -10, PowerStyle::Per), "per one thousand twenty-four");
+    assert_eq!(power_to_words_with(1, -5, PowerStyle::Per), "per one");
+    assert_eq!(power_to_words_with(10, 0, PowerStyle::Per), power_to_words(10, 0));
+    assert_eq!(power_to_words_with(-10, -3, PowerStyle::Per), power_to_words(-10, -3));
+    assert_eq!(power_to_words_with(10, -39, PowerStyle::Per), power_to_words(10, -39));
+    assert_eq!(power_to_words_with(10, i128::MIN, PowerStyle::Per), power_to_words(10, i128::MIN));
+    let minus = WordsOptions::new().negative_word("minus").capitalize(true);
+    assert_eq!(minus.power_to_words(-2, -21, PowerStyle::Ordinal), "Minus two to the minus twenty-first");
+    assert_eq!(minus.power_to_words(10, -3, PowerStyle::Per), "Per thousand");
+    assert_eq!(power_to_words(2, i128::MIN), "two to the negative one hundred seventy undecillion \
+        one hundred forty-one decillion one hundred eighty-three nonillion four hundred sixty octillion \
+        four hundred sixty-nine septillion two hundred thirty-one sextillion seven hundred thirty-one \
+        quintillion six hundred eighty-seven quadrillion three hundred three trillion seven hundred \
+        fifteen billion eight hundred eighty-four million one hundred five thousand seven hundred \
+        twenty-eighth");
+}
+
+#[test]
+fn all_u16_ord_nums_are_well_formed() {
+    const ORDINAL_ENDINGS: [&str; 12] = [
+        "first", "second", "third", "fifth", "eighth", "ninth", "twelfth", "ieth", "th", "st", "nd", "rd",
+    ];
+
+    for n in 0..=u16::MAX {
+        let words = u16_to_ord_words(n);
+        let last_word = words.rsplit(|c| c == ' ' || c == '-').next().unwrap();
+        assert!(ORDINAL_ENDINGS.iter().any(|ending| last_word.ends_with(ending)),
+            "input: '{}' output: '{}' doesn't have an ordinal ending", n, words);
+        assert!(!last_word.ends_with("yth") && !last_word.ends_with("yeth"),
+            "input: '{}' output: '{}' has a malformed \"-ieth\" ending", n, words);
+        assert!(!words.contains("  ") && !words.starts_with(' ') && !words.ends_with(' '),
+            "input: '{}' output: '{}' has stray spaces", n, words);
+        assert!(!words.contains("- ") && !words.contains(" -") && !words.starts_with('-') && !words.ends_with('-'),
+            "input: '{}' output: '{}' has a dangling hyphen", n, words);
+        assert_eq!(words.matches(|c: char| !c.is_ascii_lowercase() && c != ' ' && c != '-').count(), 0,
+            "input: '{}' output: '{}' has unexpected characters", n, words);
+    }
+}
+
+#[test]
+fn mixed_nums() {
+    assert_eq!(mixed_number_words("2 1/2"), Ok("two and a half".to_string()));
+    assert_eq!(mixed_number_words("1 3/4"), Ok("one and three quarters".to_string()));
+    assert_eq!(mixed_number_words("1 1/4"), Ok("one and a quarter".to_string()));
+    assert_eq!(mixed_number_words("3 1/3"), Ok("three and a third".to_string()));
+    assert_eq!(mixed_number_words("10 1/11"), Ok("ten and an eleventh".to_string()));
+    assert_eq!(mixed_number_words("4 3/2"), Ok("four and three halves".to_string()));
+    assert_eq!(mixed_number_words("1/2"), Ok("one half".to_string()));
+    assert_eq!(mixed_number_words("1/100"), Ok("one hundredth".to_string()));
+    assert_eq!(mixed_number_words("2 7/1000"), Ok("two and seven thousandths".to_string()));
+    assert_eq!(mixed_number_words("1/101"), Ok("one one-hundred-first".to_string()));
+    assert_eq!(mixed_number_words("3/20"), Ok("three twentieths".to_string()));
+    assert_eq!(mixed_number_words("0/2"), Ok("zero halves".to_string()));
+    assert_eq!(mixed_number_words("12"), Ok("twelve".to_string()));
+    assert_eq!(mixed_number_words("-3/4"), Ok("negative three quarters".to_string()));
+    assert_eq!(mixed_number_words("-0"), str_to_words("-0"));
+    assert_eq!(mixed_number_words("-0/2"), Ok("zero halves".to_string()));
+    assert_eq!(mixed_number_words("-0 1/8"), Ok("negative zero and an eighth".to_string()));
+    assert_eq!(mixed_number_words("+1 1/2"), Ok("one and a half".to_string()));
+    assert_eq!(mixed_number_words("2 1/18"), Ok("two and an eighteenth".to_string()));
+    assert_eq!(mixed_number_words("2 1/80"), Ok("two and an eightieth".to_string()));
+
+    for invalid in ["", "-", " 1/2", "2 ", "2  1/2", "2 1", "2 /2", "2 1/", "1/1", "a/2", "2 1/2 ", "2.5 1/2"].iter() {
+        assert_eq!(mixed_number_words(invalid), Err(StrConversionError::InvalidString), "input: '{}'", invalid);
+    }
+    assert_eq!(mixed_number_words("340282366920938463463374607431768211456"), Err(StrConversionError::TooLarge));
+}
+
+#[test]
+fn building_nums_represented_by_u16() {
+    test_func("u16_building_nums.csv",
+        |i| i.parse().unwrap(),
+        |o| o.to_string(),
+        u16_to_building_words);
+}
+
+#[test]
+fn spelled_or_digits() {
+    assert_eq!(spell_or_digits(0, 0), "zero");
+    assert_eq!(spell_or_digits(1, 0), "1");
+    assert_eq!(spell_or_digits(99, 99), "ninety-nine");
+    assert_eq!(spell_or_digits(1_000_000, 99), "1000000");
+    assert_eq!(spell_or_digits(u128::MAX, u128::MAX), u128_to_words(u128::MAX));
+}
+
+#[test]
+fn spelled_letters() {
+    assert_eq!(spell_letters(""), "");
+    assert_eq!(spell_letters("zero"), "z, e, r, o");
+    assert_eq!(spell_letters("ABC 1ß!"), "a, b, c, 1");
+    assert_eq!(spell_number_letters(0), "zero: z, e, r, o");
+    assert_eq!(spell_number_letters(21), "twenty-one: t, w, e, n, t, y, hyphen, o, n, e");
+    assert_eq!(spell_number_letters(1_000_000), "one million: o, n, e; m, i, l, l, i, o, n");
+}
+
+#[test]
+fn hundreds_style_nums_represented_by_u16() {
+    test_func("u16_hundreds_style_nums.csv",
+        |i| i.parse().unwrap(),
+        |o| o.to_string(),
+        u16_to_words_hundreds_style);
+}
+
+#[test]
+fn word_spans() {
+    for (input, expected_output) in get_inputs_and_expected_outputs("u128_nums.csv") {
+        let (words, spans) = u128_to_words_spans(input.parse().unwrap());
+        assert_eq!(words, expected_output);
+
+        let mut rebuilt = String::new();
+        for (range, kind) in spans {
+            if !rebuilt.is_empty() && kind != TokenKind::Connector && !rebuilt.ends_with('-') {
+                rebuilt.push(' ');
+            }
+            let token = &words[range];
+            match kind {
+                TokenKind::Connector => assert_eq!(token, "-"),
+                TokenKind::Hundred => assert_eq!(token, "hundred"),
+                TokenKind::Period => assert!(PERIODS.contains(&token), "'{}' is not a period", token),
+                TokenKind::Digit => assert!((0..10).any(|d| u8_to_words(d) == token), "'{}' is not a digit", token),
+                TokenKind::Tens => assert!((10..100).any(|t| u8_to_words(t) == token), "'{}' is not a tens word", token),
+            }
+            rebuilt.push_str(token);
+        }
+        assert_eq!(rebuilt, expected_output);
+    }
+}
+
+#[test]
+fn nums_represented_by_str_with_decimal_denominator() {
+    test_result_func("str_nums_decimal_denominator.csv",
+        |i| i.to_string(),
+        |o| Ok(o.to_string()),
+        |x| str_to_words_decimal_denominator(&x));
+
+    test_result_func("str_nums_err_invalid.csv",
+        |i| i.to_string(),
+        |_o| Err(StrConversionError::InvalidString),
+        |x| str_to_words_decimal_denominator(&x));
+
+    test_result_func("str_nums_err_too-large.csv",
+        |i| i.to_string(),
+        |_o| Err(StrConversionError::TooLarge),
+        |x| str_to_words_decimal_denominator(&x));
+
+    assert_eq!(str_to_words_decimal_denominator("0.000000000000000000000000000000000000001"),
+        Err(StrConversionError::TooLarge));
+}
+
+#[test]
+fn sign_positions() {
+    fn move_sign_to_end(words: &str) -> String {
+        match words.strip_prefix("negative ") {
+            Some(magnitude) => magnitude.to_string() + " negative",
+            None => words.to_string(),
+        }
+    }
+
+    for (filename, func) in [
+        ("i16_nums.csv", (|n: &str| i16_to_words_with_sign(n.parse().unwrap(), SignPosition::Suffix)) as fn(&str) -> String),
+        ("i32_nums.csv", |n| i32_to_words_with_sign(n.parse().unwrap(), SignPosition::Suffix)),
+        ("i64_nums.csv", |n| i64_to_words_with_sign(n.parse().unwrap(), SignPosition::Suffix)),
+        ("i128_nums.csv", |n| i128_to_words_with_sign(n.parse().unwrap(), SignPosition::Suffix)),
+        ("i8_nums.csv", |n| i8_to_words_with_sign(n.parse().unwrap(), SignPosition::Suffix)),
+        ("str_nums_ok.csv", |n| str_to_words_with_sign(n, SignPosition::Suffix).unwrap()),
+    ].iter() {
+        for (input, expected_output) in get_inputs_and_expected_outputs(filename) {
+            assert_eq!(func(&input), move_sign_to_end(&expected_output), "input: '{}'", input);
+        }
+    }
+
+    assert_eq!(i128_to_words_with_sign(-5, SignPosition::Prefix), "negative five");
+    assert_eq!(isize_to_words_with_sign(-5, SignPosition::Suffix), "five negative");
+    assert_eq!(str_to_words_with_sign("-.5", SignPosition::Suffix), Ok("point five negative".to_string()));
+    assert_eq!(str_to_words_with_sign("-", SignPosition::Suffix), Err(StrConversionError::InvalidString));
+
+    let minus_suffix = WordsOptions::new().negative_word("minus").sign_position(SignPosition::Suffix);
+    assert_eq!(minus_suffix.i128_to_words(-42), "forty-two minus");
+    assert_eq!(minus_suffix.i8_to_words(i8::MIN), "one hundred twenty-eight minus");
+    assert_eq!(minus_suffix.i128_to_words(42), "forty-two");
+    assert_eq!(minus_suffix.str_to_words("-0.5"), Ok("zero point five minus".to_string()));
+    assert_eq!(minus_suffix.clone().capitalize(true).i32_to_words(-3), "Three minus");
+    assert_eq!(
+        minus_suffix.fraction_style(FractionStyle::Denominator).str_to_words("-2.5"),
+        Ok("two and five tenths minus".to_string())
+    );
+}
+
+#[test]
+fn tallies() {
+    assert_eq!(tally_words(0), "zero");
+    assert_eq!(tally_words(6), "six");
+    assert_eq!(tally_words(12), "one dozen");
+    assert_eq!(tally_words(16), "one and a third dozen");
+    assert_eq!(tally_words(20), "one and two thirds dozen");
+    assert_eq!(tally_words(21), "one and three quarters dozen");
+    assert_eq!(tally_words(42), "three and a half dozen");
+    assert_eq!(tally_words(43), "forty-three");
+    assert_eq!(tally_words(1200), "one hundred dozen");
+
+    assert_eq!(tally_words_with(87, TallyBase::Score), "eighty-seven");
+    assert_eq!(tally_words_with(85, TallyBase::Score), "four and a quarter score");
+    assert_eq!(tally_words_with(288, TallyBase::Gross), "two gross");
+    assert_eq!(tally_words_with(360, TallyBase::Gross), "two and a half gross");
+    assert_eq!(tally_words_with(100, TallyBase::Gross), "one hundred");
+}
+
+#[test]
+fn relative_times() {
+    assert_eq!(relative_time_words(0), "now");
+    assert_eq!(relative_time_words(-1), "one second ago");
+    assert_eq!(relative_time_words(59), "in fifty-nine seconds");
+    assert_eq!(relative_time_words(-60), "one minute ago");
+    assert_eq!(relative_time_words(119), "in one minute");
+    assert_eq!(relative_time_words(-7200), "two hours ago");
+    assert_eq!(relative_time_words(86_399), "in twenty-three hours");
+    assert_eq!(relative_time_words(86_400 * 3), "in three days");
+    assert_eq!(relative_time_words(i64::MIN), "one hundred six trillion seven hundred fifty-one billion \
+        nine hundred ninety-one million one hundred sixty-seven thousand three hundred days ago");
+}
+
+#[test]
+fn word_overrides() {
+    assert_eq!(override_words("", &[("zero", "nought")]), "");
+    assert_eq!(override_words("one hundred one", &[]), "one hundred one");
+    assert_eq!(override_words("one hundred one", &[("one", "a")]), "a hundred a");
+    assert_eq!(override_words("twenty-one thousand", &[("twenty", "score"), ("thousand", "k")]), "score-one k");
+    assert_eq!(override_words("seventy", &[("seven", "7")]), "seventy");
+    assert_eq!(override_words("one", &[("one", "first"), ("one", "second")]), "first");
+
+    for (input, expected_output) in get_inputs_and_expected_outputs("str_nums_ok.csv") {
+        assert_eq!(str_to_words_with_overrides(&input, &[]), Ok(expected_output));
+    }
+    assert_eq!(str_to_words_with_overrides("-1.0", &[("negative", "minus"), ("zero", "oh")]),
+        Ok("minus one point oh".to_string()));
+    assert_eq!(str_to_words_with_overrides("x", &[]), Err(StrConversionError::InvalidString));
+}
+
+#[test]
+fn scientific_nums() {
+    assert_eq!(scientific_words(0), "zero");
+    assert_eq!(scientific_words(1), "one times ten to the zeroth");
+    assert_eq!(scientific_words(10), "one times ten");
+    assert_eq!(scientific_words(101), "one point zero one times ten to the second");
+    assert_eq!(scientific_words(1_230_000), "one point two three times ten to the sixth");
+    assert_eq!(scientific_words(u128::MAX), "three point four zero two eight two three six six nine two zero nine \
+        three eight four six three four six three three seven four six zero seven four three one seven six eight \
+        two one one four five five times ten to the thirty-eighth");
+}
+
+#[test]
+fn ord_nums_ending_with_tens_or_periods() {
+    test_func("u128_ord_round_nums.csv",
+        |i| i.parse().unwrap(),
+        |o| o.to_string(),
+        u128_to_ord_words);
+}
+
+#[test]
+fn nums_with_custom_groups() {
+    test_func("u128_nums.csv",
+        |i| i.parse().unwrap(),
+        |o| o.to_string(),
+        |n| to_words_with_groups(n, &[3], &PERIODS).unwrap());
+    test_func("u128_nums.csv",
+        |i| i.parse().unwrap(),
+        |o| o.to_string(),
+        |n| to_words_with_groups(n, &[3, 3, 3], &PERIODS).unwrap());
+
+    let indian = ["thousand", "lakh", "crore"];
+    assert_eq!(to_words_with_groups(0, &[3, 2], &indian).unwrap(), "zero");
+    assert_eq!(to_words_with_groups(100_000, &[3, 2], &indian).unwrap(), "one lakh");
+    assert_eq!(to_words_with_groups(123_456, &[3, 2], &indian).unwrap(), "one lakh twenty-three thousand four hundred fifty-six");
+    assert_eq!(to_words_with_groups(1_000_000_000, &[3, 2], &indian).unwrap(), "one hundred crore");
+    assert_eq!(to_words_with_groups(123_456_789_012, &[3, 2], &indian).unwrap(),
+        "twelve thousand three hundred forty-five crore sixty-seven lakh eighty-nine thousand twelve");
+
+    let myriads = ["myriad"];
+    assert_eq!(to_words_with_groups(12_345_678, &[4], &myriads).unwrap(),
+        "one thousand two hundred thirty-four myriad five thousand six hundred seventy-eight");
+    assert_eq!(to_words_with_groups(100_000_000, &[4], &myriads).unwrap(), "one myriad myriad");
+
+    assert_eq!(to_words_with_groups(1_234, &[3], &[]).unwrap(), "one thousand two hundred thirty-four");
+    assert_eq!(to_words_with_groups(u128::MAX, &[100], &["googol"]).unwrap(), u128_to_words(u128::MAX));
+    // one group per digit; the 35 non-zero digits above the ones are named
+    let words = to_words_with_groups(u128::MAX, &[1], &["x"; 50]).unwrap();
+    assert_eq!(words.split(' ').filter(|&word| word == "x").count(), 35);
+}
+
+#[test]
+fn nums_with_invalid_group_sizes() {
+    assert_eq!(to_words_with_groups(1, &[], &[]), Err(GroupingError::InvalidGroupSizes));
+    assert_eq!(to_words_with_groups(1, &[3, 0], &["thousand", "lakh"]), Err(GroupingError::InvalidGroupSizes));
+    assert_eq!(to_words_with_groups(0, &[0], &[]), Err(GroupingError::InvalidGroupSizes));
+    assert_eq!(GroupingError::InvalidGroupSizes.message(), "group sizes must be non-empty and non-zero");
+}
+
+#[test]
+fn abbreviated_nums() {
+    let abbreviations = SPOKEN_SCALE_ABBREVIATIONS;
+    assert_eq!(u128_to_abbreviated_words(0, 1, &abbreviations), "zero");
+    assert_eq!(u128_to_abbreviated_words(999, 1, &abbreviations), "nine hundred ninety-nine");
+    assert_eq!(u128_to_abbreviated_words(1000, 1, &abbreviations), "one K");
+    assert_eq!(u128_to_abbreviated_words(1_050, 1, &abbreviations), "one K");
+    assert_eq!(u128_to_abbreviated_words(1_050, 2, &abbreviations), "one point zero five K");
+    assert_eq!(u128_to_abbreviated_words(1_050, 9, &abbreviations), "one point zero five K");
+    assert_eq!(u128_to_abbreviated_words(999_999, 1, &abbreviations), "nine hundred ninety-nine point nine K");
+    assert_eq!(u128_to_abbreviated_words(1_500_000, 0, &abbreviations), "one mil");
+    assert_eq!(u128_to_abbreviated_words(2_750_000_000, 2, &abbreviations), "two point seven five bil");
+    assert_eq!(u128_to_abbreviated_words(4_000_000_000_000_000, 1, &abbreviations), "four quadrillion");
+    assert_eq!(u128_to_abbreviated_words(1_500_000, 1, &[]), "one point five million");
+    assert_eq!(u128_to_abbreviated_words(u128::MAX, 3, &abbreviations), "three hundred forty point two eight two undecillion");
+}
+
+#[test]
+fn error_messages() {
+    assert_eq!(DigitConversionError::InvalidCharacter.message(), "string contains a character that is not a digit");
+    assert_eq!(PaddedDigitConversionError::TooManyDigits.message(), "number has more digits than the requested width");
+    assert_eq!(PaddedDigitConversionError::WidthTooLarge.message(), "requested width is too large");
+    assert_eq!(StrConversionError::InvalidString.message(), "input string is not a valid number");
+    assert_eq!(StrConversionError::TooLarge.message(), "number is too large to convert (max is 2^128 - 1)");
+    assert_eq!(FloatConversionError::NotFinite.message(), "number is not finite");
+    assert_eq!(FloatConversionError::TooLarge.message(), "number is too large to convert (max is 2^128 - 1)");
+
+    assert_eq!(format!("{}", DigitConversionError::InvalidCharacter), DigitConversionError::InvalidCharacter.message());
+    assert_eq!(format!("{}", StrConversionError::TooLarge), "number is too large to convert (max is 2^128 - 1)");
+    assert_eq!(FloatConversionError::NotFinite.to_string(), FloatConversionError::NotFinite.message());
+}
+
+#[test]
+fn counts_of_items_with_noun_fn() {
+    let mice = |count| if count == 1 { "mouse" } else { "mice" };
+    assert_eq!(count_to_words_fn(0, mice), "zero mice");
+    assert_eq!(count_to_words_fn(1, mice), "one mouse");
+    assert_eq!(count_to_words_fn(101, mice), "one hundred one mice");
+
+    let three_forms = |count| match count { 0 => "zeroes", 1 => "one", _ => "many" };
+    assert_eq!(count_to_words_fn(0, three_forms), "zero zeroes");
+    assert_eq!(count_to_words_fn(1, three_forms), "one one");
+    assert_eq!(count_to_words_fn(5, three_forms), "five many");
+}
+
+#[test]
+fn bearings() {
+    assert_eq!(bearing_to_words(0), Ok("due north".to_string()));
+    assert_eq!(bearing_to_words(1), Ok("one degree, north".to_string()));
+    assert_eq!(bearing_to_words(22), Ok("twenty-two degrees, north".to_string()));
+    assert_eq!(bearing_to_words(23), Ok("twenty-three degrees, northeast".to_string()));
+    assert_eq!(bearing_to_words(90), Ok("due east".to_string()));
+    assert_eq!(bearing_to_words(180), Ok("due south".to_string()));
+    assert_eq!(bearing_to_words(225), Ok("two hundred twenty-five degrees, southwest".to_string()));
+    assert_eq!(bearing_to_words(337), Ok("three hundred thirty-seven degrees, northwest".to_string()));
+    assert_eq!(bearing_to_words(338), Ok("three hundred thirty-eight degrees, north".to_string()));
+    assert_eq!(bearing_to_words(359), Ok("three hundred fifty-nine degrees, north".to_string()));
+    assert_eq!(bearing_to_words(360), Err(BearingConversionError::OutOfRange));
+    assert_eq!(bearing_to_words(u16::MAX), Err(BearingConversionError::OutOfRange));
+
+    assert_eq!(bearing_to_words_with(11, CompassPoints::Sixteen), Ok("eleven degrees, north".to_string()));
+    assert_eq!(bearing_to_words_with(12, CompassPoints::Sixteen), Ok("twelve degrees, north-northeast".to_string()));
+    assert_eq!(bearing_to_words_with(45, CompassPoints::Sixteen), Ok("forty-five degrees, northeast".to_string()));
+    assert_eq!(bearing_to_words_with(290, CompassPoints::Sixteen), Ok("two hundred ninety degrees, west-northwest".to_string()));
+    assert_eq!(bearing_to_words_with(348, CompassPoints::Sixteen), Ok("three hundred forty-eight degrees, north-northwest".to_string()));
+    assert_eq!(bearing_to_words_with(349, CompassPoints::Sixteen), Ok("three hundred forty-nine degrees, north".to_string()));
+    assert_eq!(bearing_to_words_with(350, CompassPoints::Sixteen), Ok("three hundred fifty degrees, north".to_string()));
+    assert_eq!(bearing_to_words_with(400, CompassPoints::Sixteen), Err(BearingConversionError::OutOfRange));
+
+    assert_eq!(format!("{}", BearingConversionError::OutOfRange), "bearing must be between 0 and 359 degrees");
+}
+
+#[test]
+fn float_signed_zeros() {
+    assert_eq!(f32_to_words_with_signed_zero(-0.0, SignedZero::Preserve), Ok("negative zero".to_string()));
+    assert_eq!(f32_to_words_with_signed_zero(0.0, SignedZero::Preserve), Ok("zero".to_string()));
+    assert_eq!(f32_to_words_with_signed_zero(-0.0, SignedZero::Suppress), Ok("zero".to_string()));
+    assert_eq!(f32_to_words_with_signed_zero(0.0, SignedZero::Suppress), Ok("zero".to_string()));
+    assert_eq!(f64_to_words_with_signed_zero(-0.0, SignedZero::Preserve), Ok("negative zero".to_string()));
+    assert_eq!(f64_to_words_with_signed_zero(0.0, SignedZero::Preserve), Ok("zero".to_string()));
+    assert_eq!(f64_to_words_with_signed_zero(-0.0, SignedZero::Suppress), Ok("zero".to_string()));
+    assert_eq!(f64_to_words_with_signed_zero(0.0, SignedZero::Suppress), Ok("zero".to_string()));
+    assert_eq!(f32_to_words(-0.0), Ok("zero".to_string()));
+    assert_eq!(f64_to_words(-0.0), Ok("zero".to_string()));
+
+    assert_eq!(f64_to_words_with_signed_zero(-0.5, SignedZero::Suppress), Ok("negative zero point five".to_string()));
+    assert_eq!(f64_to_words_with_signed_zero(f64::NAN, SignedZero::Preserve), Err(FloatConversionError::NotFinite));
+    assert_eq!(f64_to_words_with_signed_zero(-1e39, SignedZero::Suppress), Err(FloatConversionError::TooLarge));
+}
+
+#[test]
+fn base_narrations() {
+    assert_eq!(base_narration(0, 2).unwrap(), "zero in binary");
+    assert_eq!(base_narration(13, 2).unwrap(), "one one zero one in binary");
+    assert_eq!(base_narration(64, 8).unwrap(), "one zero zero in octal");
+    assert_eq!(base_narration(1907, 10).unwrap(), "one nine zero seven in decimal");
+    assert_eq!(base_narration(0xCAFE, 16).unwrap(), "c a f e in hexadecimal");
+    assert_eq!(base_narration(35, 36).unwrap(), "z in base thirty-six");
+    assert_eq!(base_narration(u128::MAX, 16).unwrap(), ["f"; 32].join(" ") + " in hexadecimal");
+}
+
+#[test]
+fn base_narration_with_invalid_radix() {
+    for radix in [0, 1, 37, u32::MAX] {
+        assert_eq!(base_narration(1, radix), Err(RadixError::Unsupported), "{}", radix);
+    }
+    assert_eq!(ConversionError::from(RadixError::Unsupported).message(), "radix must be between 2 and 36");
+}
+
+#[test]
+fn nth_nouns() {
+    assert_eq!(the_nth_noun(1, "step", OrdinalStyle::Words), "the first step");
+    assert_eq!(the_nth_noun(3, "chapter", OrdinalStyle::Words), "the third chapter");
+    assert_eq!(the_nth_noun(21, "century", OrdinalStyle::Words), "the twenty-first century");
+
+    assert_eq!(the_nth_noun(0, "item", OrdinalStyle::Suffix), "the 0th item");
+    assert_eq!(the_nth_noun(1, "place", OrdinalStyle::Suffix), "the 1st place");
+    assert_eq!(the_nth_noun(2, "place", OrdinalStyle::Suffix), "the 2nd place");
+    assert_eq!(the_nth_noun(3, "chapter", OrdinalStyle::Suffix), "the 3rd chapter");
+    assert_eq!(the_nth_noun(11, "hour", OrdinalStyle::Suffix), "the 11th hour");
+    assert_eq!(the_nth_noun(12, "night", OrdinalStyle::Suffix), "the 12th night");
+    assert_eq!(the_nth_noun(113, "floor", OrdinalStyle::Suffix), "the 113th floor");
+    assert_eq!(the_nth_noun(101, "dalmatian", OrdinalStyle::Suffix), "the 101st dalmatian");
+    assert_eq!(the_nth_noun(1022, "row", OrdinalStyle::Suffix), "the 1022nd row");
+}
+
+#[test]
+fn spell_macro() {
+    assert_eq!(spell!(0), "zero");
+    assert_eq!(spell!(42), "forty-two");
+    assert_eq!(spell!(255u8), "two hundred fifty-five");
+    assert_eq!(spell!(-128i8), "negative one hundred twenty-eight");
+    assert_eq!(spell!(5_000_000_000u64), "five billion");
+    assert_eq!(spell!(u128::MAX), u128_to_words(u128::MAX));
+    assert_eq!(spell!(i128::MIN), i128_to_words(i128::MIN));
+    assert_eq!(spell!(3usize), "three");
+    assert_eq!(spell!(-3isize), "negative three");
+
+    let n: u16 = 1907;
+    assert_eq!(spell!(n), "one thousand nine hundred seven");
+    assert_eq!(spell!(n as i64 * -2), "negative three thousand eight hundred fourteen");
+}
+
+#[test]
+fn sequence_narrations() {
+    assert_eq!(
+        sequence_narration(&[5, -3, 10], "; ", true),
+        "first: five; second: negative three; third: ten; total: twelve"
+    );
+    assert_eq!(sequence_narration(&[5, -3, 10], "; ", false), "first: five; second: negative three; third: ten");
+    assert_eq!(sequence_narration(&[-1, 1], " / ", true), "first: negative one / second: one / total: zero");
+    assert_eq!(sequence_narration(&[], "; ", true), "total: zero");
+    assert_eq!(sequence_narration(&[], "; ", false), "");
+    assert_eq!(
+        sequence_narration(&[i64::MAX, i64::MAX], "; ", true),
+        format!(
+            "first: {0}; second: {0}; total: {1}",
+            i64_to_words(i64::MAX),
+            i128_to_words(i64::MAX as i128 * 2)
+        )
+    );
+}
+
+#[test]
+fn indefinite_articles() {
+    for (word, article) in [
+        ("apple", "an"), ("Egg", "an"), ("igloo", "an"), ("owl", "an"), ("umbrella", "an"),
+        ("eight", "an"), ("eleventh", "an"), ("hour", "an"), ("honest", "an"), ("heiress", "an"),
+        ("banana", "a"), ("hundred", "a"), ("thousand", "a"), ("one-off", "a"), ("unicorn", "a"),
+        ("user", "a"), ("European", "a"), ("ewe", "a"), ("hotel", "a"), ("", "a"),
+        ("unimportant", "an"), ("uninformed", "an"), ("unidentified", "an"), ("uniform", "a"),
+        ("1", "a"), ("8", "an"), ("11", "an"), ("18", "an"), ("80", "an"), ("0", "a"),
+        ("1,000", "a"), ("8-year-old", "an"), ("18th", "an"), ("11000000000000000000000000000000000000000000", "an"),
+    ] {
+        assert_eq!(indefinite_article(word), article, "{}", word);
+    }
+}
+
+#[test]
+fn spelled_with_articles() {
+    assert_eq!(spell_with_article(0, "apples"), "zero apples");
+    assert_eq!(spell_with_article(1, "apple"), "an apple");
+    assert_eq!(spell_with_article(1, "pear"), "a pear");
+    assert_eq!(spell_with_article(1, "hour"), "an hour");
+    assert_eq!(spell_with_article(2, "hours"), "two hours");
+    assert_eq!(spell_with_article(8, "owls"), "eight owls");
+    assert_eq!(spell_with_article(100, "sheep"), "a hundred sheep");
+    assert_eq!(spell_with_article(101, "dalmatians"), "a hundred one dalmatians");
+    assert_eq!(spell_with_article(1_000, "years"), "a thousand years");
+    assert_eq!(spell_with_article(1_000_000, "stars"), "a million stars");
+    assert_eq!(spell_with_article(21, "guns"), "twenty-one guns");
+    assert_eq!(spell_with_article(201, "pages"), "two hundred one pages");
+}
+
+#[test]
+fn percent_ranges() {
+    let ok = |s: &str| percent_range_words(s).unwrap();
+    assert_eq!(ok("10-20%"), "ten to twenty percent");
+    assert_eq!(ok("0-100%"), "zero to one hundred percent");
+    assert_eq!(ok("1.5-2.25%"), "one point five to two point two five percent");
+    assert_eq!(ok("-10-10%"), "negative ten to ten percent");
+    assert_eq!(ok("-10--5%"), "negative ten to negative five percent");
+    assert_eq!(ok(".5-1%"), "point five to one percent");
+
+    for invalid in ["10-20", "10%-20%", "10%-20", "-20%", "20%", "10-%", "-10%", "10--%", "1 0-20%", "10-20%%", ""] {
+        assert_eq!(percent_range_words(invalid), Err(StrConversionError::InvalidString), "{}", invalid);
+    }
+}
+
+#[test]
+fn words_with_connectors() {
+    let test = |n, separator, connector, expected: &str| {
+        assert_eq!(u128_to_words_with_connectors(n, separator, connector, true), expected, "{}", n);
+    };
+    test(0, ", ", "and", "zero");
+    test(5, ", ", "and", "five");
+    test(105, ", ", "and", "one hundred five");
+    test(1_005, ", ", "and", "one thousand, and five");
+    test(1_200_005, ", ", "and", "one million, two hundred thousand, and five");
+    test(1_200_000, ", ", "and", "one million, two hundred thousand");
+    test(7_000_000_042, ", ", "and", "seven billion, and forty-two");
+    test(1_200_005, ", ", "", "one million, two hundred thousand, five");
+    test(1_200_005, " ", "and", "one million two hundred thousand and five");
+    test(1_200_005, " ", "plus", "one million two hundred thousand plus five");
+    test(12_345_678, "; ", "and", "twelve million; three hundred forty-five thousand; and six hundred seventy-eight");
+
+    for n in [0, 1, 999, 1_000, 1_001, 123_456_789, u64::MAX as u128, u128::MAX] {
+        assert_eq!(u128_to_words_with_connectors(n, " ", "", true), u128_to_words(n));
+        assert_eq!(u128_to_words_with_connectors(n, " ", "and", false), u128_to_words_with_connectors(n, " ", "and", true));
+    }
+
+    assert_eq!(u128_to_words_with_connectors(1_005, ", ", "and", false), "one thousand and five");
+    assert_eq!(u128_to_words_with_connectors(1_200_005, ", ", "", false), "one million, two hundred thousand, five");
+    assert_eq!(
+        u128_to_words_with_connectors(12_345_678, "; ", "and", false),
+        "twelve million; three hundred forty-five thousand and six hundred seventy-eight"
+    );
+
+    let options = WordsOptions::new().british(true).integer_style(IntegerStyle::Commas);
+    assert_eq!(options.clone().oxford_comma(true).u128_to_words(1_001_001), "one million, one thousand, and one");
+    assert_eq!(options.oxford_comma(false).u128_to_words(1_001_001), "one million, one thousand and one");
+    assert_eq!(WordsOptions::new().oxford_comma(true).u128_to_words(1_001_001), "one million one thousand one");
+}
+
+#[test]
+fn comparisons() {
+    use CompareStyle::*;
+    assert_eq!(compare_words(7, 3, GreaterThan), "seven is greater than three");
+    assert_eq!(compare_words(3, 7, GreaterThan), "three is less than seven");
+    assert_eq!(compare_words(3, 3, GreaterThan), "three is equal to three");
+    assert_eq!(compare_words(7, 3, MoreThan), "seven is more than three");
+    assert_eq!(compare_words(3, 7, MoreThan), "three is less than seven");
+    assert_eq!(compare_words(0, 0, MoreThan), "zero is equal to zero");
+    assert_eq!(compare_words(7, 3, Symbol), "seven > three");
+    assert_eq!(compare_words(-7, -3, Symbol), "negative seven < negative three");
+    assert_eq!(compare_words(-1, -1, Symbol), "negative one = negative one");
+    assert_eq!(
+        compare_words(i128::MIN, i128::MAX, GreaterThan),
+        i128_to_words(i128::MIN) + " is less than " + &i128_to_words(i128::MAX)
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn nums_read_from_reader() {
+    // Reads at most `chunk_size` bytes at a time, to exercise values split across reads
+    struct ChunkedReader<'a> {
+        bytes: &'a [u8],
+        chunk_size: usize,
+    }
+    impl std::io::Read for ChunkedReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let len = self.bytes.len().min(self.chunk_size).min(buf.len());
+            buf[..len].copy_from_slice(&self.bytes[..len]);
+            self.bytes = &self.bytes[len..];
+            Ok(len)
+        }
+    }
+
+    let inputs_and_outputs = get_inputs_and_expected_outputs("str_nums_ok.csv");
+    for chunk_size in [1, 2, 3, 4096] {
+        for (input, expected) in &inputs_and_outputs {
+            let mut words = String::new();
+            let reader = ChunkedReader { bytes: input.as_bytes(), chunk_size };
+            assert_eq!(read_to_words(reader, &mut words), Ok(()), "{}", input);
+            assert_eq!(&words, expected, "{}", input);
+        }
+    }
+
+    for file in ["str_nums_err_invalid.csv", "str_nums_err_too-large.csv"] {
+        for (input, expected) in get_inputs_and_expected_outputs(file) {
+            let mut words = "unchanged".to_string();
+            let reader = ChunkedReader { bytes: input.as_bytes(), chunk_size: 2 };
+            assert_eq!(read_to_words(reader, &mut words), str_to_words(&input).map(|_| ()), "{} ({})", input, expected);
+            assert_eq!(words, "unchanged");
+        }
+    }
+
+    let long_fraction = "1.".to_string() + &"7".repeat(10_000);
+    let mut words = String::new();
+    read_to_words(long_fraction.as_bytes(), &mut words).unwrap();
+    assert_eq!(words, str_to_words(&long_fraction).unwrap());
+
+    let mut words = String::new();
+    assert_eq!(read_to_words("4.2e1".as_bytes(), &mut words), Err(StrConversionError::InvalidString));
+}
+
+#[test]
+fn myriad_nums() {
+    assert_eq!(u128_to_myriad_words(0), "zero");
+    assert_eq!(u128_to_myriad_words(9_999), "nine thousand nine hundred ninety-nine");
+    assert_eq!(u128_to_myriad_words(10_000), "one ten-thousand");
+    assert_eq!(u128_to_myriad_words(10_001), "one ten-thousand one");
+    assert_eq!(u128_to_myriad_words(123_456_789), "one hundred-million two thousand three hundred forty-five ten-thousand six thousand seven hundred eighty-nine");
+    assert_eq!(u128_to_myriad_words(100_000_000), "one hundred-million");
+    assert_eq!(u128_to_myriad_words(1_0000_0000_0000), "one trillion");
+    assert_eq!(u128_to_myriad_words(3_0000_0000_0020), "three trillion twenty");
+    assert_eq!(
+        u128_to_myriad_words(u128::MAX),
+        "three hundred forty undecillion \
+        two thousand eight hundred twenty-three hundred-nonillion \
+        six thousand six hundred ninety-two ten-octillion \
+        nine hundred thirty-eight septillion \
+        four thousand six hundred thirty-four hundred-quintillion \
+        six thousand three hundred thirty-seven ten-quadrillion \
+        four thousand six hundred seven trillion \
+        four thousand three hundred seventeen hundred-million \
+        six thousand eight hundred twenty-one ten-thousand \
+        one thousand four hundred fifty-five"
+    );
+}
+
+#[test]
+fn period_names_follow_their_groups() {
+    assert_eq!(u128_to_words(1_000_000), "one million");
+    assert_eq!(u128_to_words(1_000_001), "one million one");
+    assert_eq!(u128_to_words(1_000_000_000), "one billion");
+    assert_eq!(u64_to_words(1_000_000_000_000_000_000), "one quintillion");
+    assert_eq!(i32_to_words(-1_000_000_000), "negative one billion");
+    assert_eq!(u32_to_ord_words(1_000_000), "one millionth");
+    assert_eq!(u128_to_words_with_connectors(1_000_000_001, ", ", "and", true), "one billion, and one");
+
+    for n in [1_000_000u128, 1_000_001, 1_000_000_000, 10u128.pow(36), u128::MAX] {
+        let tokens = u128_to_words_tokens(n);
+        for (i, token) in tokens.iter().enumerate() {
+            if PERIODS.contains(&token.as_str()) {
+                assert!(i > 0 && !PERIODS.contains(&tokens[i - 1].as_str()) && tokens[i - 1] != "zero", "{}", n);
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn period_name_without_group() {
+    push_period(1, &mut vec!["one".to_string(), "thousand".to_string()]);
+}
+
+#[test]
+fn check_lines() {
+    assert_eq!(check_line(1_234, 0, 50, '*').unwrap(), "One Thousand Two Hundred Thirty-Four and 00/100***");
+    assert_eq!(check_line(0, 99, 20, '-').unwrap(), "Zero and 99/100-----");
+    assert_eq!(check_line(21, 5, 10, '*').unwrap(), "Twenty-One and 05/100");
+    assert_eq!(check_line(1_000_000, 50, 26, '#').unwrap(), "One Million and 50/100####");
+    assert_eq!(check_line(7, 0, 18, '·').unwrap().chars().count(), 18);
+}
+
+#[test]
+fn check_line_with_invalid_cents() {
+    assert_eq!(check_line(1, 100, 0, '*'), Err(CurrencyConversionError::InvalidCents));
+    assert_eq!(check_line(1, u8::MAX, 80, '*'), Err(CurrencyConversionError::InvalidCents));
+}
+
+#[test]
+fn plural_categories_and_messages() {
+    assert_eq!(plural_category(0), PluralCategory::Zero);
+    assert_eq!(plural_category(1), PluralCategory::One);
+    assert_eq!(plural_category(2), PluralCategory::Other);
+    assert_eq!(plural_category(21), PluralCategory::Other);
+    assert_eq!(plural_category(u128::MAX), PluralCategory::Other);
+
+    let message = |n| select_message(n, "no files", "{n} file", "{n} files");
+    assert_eq!(message(0), "no files");
+    assert_eq!(message(1), "one file");
+    assert_eq!(message(2), "two files");
+    assert_eq!(message(1_001), "one thousand one files");
+    assert_eq!(select_message(0, "{n} = {n}", "", ""), "zero = zero");
+    assert_eq!(select_message(5, "", "", "no placeholder"), "no placeholder");
+}
+
+#[test]
+fn padded_digit_words() {
+    assert_eq!(u32_to_padded_digit_words(7, 3), Ok("zero zero seven".to_string()));
+    assert_eq!(u32_to_padded_digit_words(42, 2), Ok("four two".to_string()));
+    assert_eq!(u32_to_padded_digit_words(0, 4), Ok("zero zero zero zero".to_string()));
+    assert_eq!(u32_to_padded_digit_words(0, 1), Ok("zero".to_string()));
+    assert_eq!(u32_to_padded_digit_words(1234, 6), Ok("zero zero one two three four".to_string()));
+    assert_eq!(u32_to_padded_digit_words(u32::MAX, 10), Ok("four two nine four nine six seven two nine five".to_string()));
+    assert_eq!(u32_to_padded_digit_words(100, 2), Err(PaddedDigitConversionError::TooManyDigits));
+    assert_eq!(u32_to_padded_digit_words(0, 0), Err(PaddedDigitConversionError::TooManyDigits));
+    assert_eq!(u32_to_padded_digit_words(7, MAX_PADDED_DIGIT_WIDTH).map(|words| words.split(' ').count()), Ok(MAX_PADDED_DIGIT_WIDTH));
+    assert_eq!(u32_to_padded_digit_words(7, MAX_PADDED_DIGIT_WIDTH + 1), Err(PaddedDigitConversionError::WidthTooLarge));
+    assert_eq!(u32_to_padded_digit_words(7, usize::MAX), Err(PaddedDigitConversionError::WidthTooLarge));
+}
+
+#[test]
+fn conversion_errors() {
+    fn convert_all(digits: &str, string: &str, float: f64, bearing: u16) -> Result<Vec<String>, ConversionError> {
+        Ok(vec![
+            str_digits_to_words(digits)?,
+            str_to_words(string)?,
+            f64_to_words(float)?,
+            bearing_to_words(bearing)?,
+        ])
+    }
+
+    assert!(convert_all("1", "2", 3.0, 4).is_ok());
+    assert_eq!(convert_all("x", "2", 3.0, 4), Err(ConversionError::Digit(DigitConversionError::InvalidCharacter)));
+    assert_eq!(convert_all("1", "", 3.0, 4).map(|words| words[1].clone()), Ok("".to_string()));
+    assert_eq!(convert_all("1", "2.2.", 3.0, 4), Err(ConversionError::Str(StrConversionError::InvalidString)));
+    assert_eq!(convert_all("1", "2", f64::NAN, 4), Err(ConversionError::Float(FloatConversionError::NotFinite)));
+    assert_eq!(convert_all("1", "2", 3.0, 360), Err(ConversionError::Bearing(BearingConversionError::OutOfRange)));
+
+    let error = ConversionError::from(StrConversionError::TooLarge);
+    assert_eq!(error.message(), StrConversionError::TooLarge.message());
+    assert_eq!(error.to_string(), StrConversionError::TooLarge.to_string());
+
+    let padded = || -> Result<String, ConversionError> { Ok(u32_to_padded_digit_words(123, 2)?) };
+    assert_eq!(padded(), Err(ConversionError::PaddedDigit(PaddedDigitConversionError::TooManyDigits)));
+}
+
+#[test]
+fn day_ordinals() {
+    assert_eq!(day_ordinal_words(1), Ok("first".to_string()));
+    assert_eq!(day_ordinal_words(12), Ok("twelfth".to_string()));
+    assert_eq!(day_ordinal_words(22), Ok("twenty-second".to_string()));
+    assert_eq!(day_ordinal_words(0), Err(DayConversionError::OutOfRange));
+    assert_eq!(day_ordinal_words(32), Err(DayConversionError::OutOfRange));
+
+    let suffixes = (1..=31).map(|day| day_ordinal_suffix(day).unwrap()).collect::<Vec<_>>();
+    assert_eq!(suffixes[..4], ["1st", "2nd", "3rd", "4th"]);
+    assert_eq!(suffixes[10..13], ["11th", "12th", "13th"]);
+    assert_eq!(suffixes[20..24], ["21st", "22nd", "23rd", "24th"]);
+    assert_eq!(suffixes[29..], ["30th", "31st"]);
+    assert_eq!(day_ordinal_suffix(255), Err(DayConversionError::OutOfRange));
+
+    assert_eq!(date_ordinal("June", 3), Ok("June third".to_string()));
+    assert_eq!(date_ordinal("March", 31), Ok("March thirty-first".to_string()));
+    assert_eq!(date_ordinal("February", 0), Err(DayConversionError::OutOfRange));
+
+    assert_eq!(DayConversionError::OutOfRange.message(), "day of the month must be between 1 and 31");
+    assert_eq!(ConversionError::from(DayConversionError::OutOfRange).to_string(), DayConversionError::OutOfRange.message());
+}
+
+#[test]
+fn nums_represented_by_str_with_repetend() {
+    let ok = |s: &str| str_to_words_repeating(s).unwrap();
+    assert_eq!(ok("0.(3)"), "zero point three repeating");
+    assert_eq!(ok("0.1(6)"), "zero point one six repeating");
+    assert_eq!(ok("-1.(142857)"), "negative one point one four two eight five seven repeating");
+    assert_eq!(ok(".(09)"), "point zero nine repeating");
+    assert_eq!(ok("12"), "twelve");
+    assert_eq!(ok("12.5"), "twelve point five");
+
+    for invalid in ["0.(3", "0.3)", "0.()", "0.(3)4", "0.((3))", "0.(3)(3)", "(3)", "1(3)", "0.(a)", "0.(3.)", "0.(-3)", "0..(3)"] {
+        assert_eq!(str_to_words_repeating(invalid), Err(StrConversionError::InvalidString), "{}", invalid);
+    }
+
+    for (input, expected) in get_inputs_and_expected_outputs("str_nums_ok.csv") {
+        assert_eq!(str_to_words_repeating(&input), Ok(expected), "{}", input);
+    }
+}
+
+#[test]
+fn expanded_forms() {
+    assert_eq!(expanded_form_words(0, true), "zero");
+    assert_eq!(expanded_form_words(0, false), "zero");
+    assert_eq!(expanded_form_words(7, true), "seven ones");
+    assert_eq!(expanded_form_words(11, true), "one ten plus one one");
+    assert_eq!(expanded_form_words(3405, true), "three thousand plus four hundred plus zero tens plus five ones");
+    assert_eq!(expanded_form_words(3405, false), "three thousand plus four hundred plus five ones");
+    assert_eq!(expanded_form_words(1000, false), "one thousand");
+    assert_eq!(expanded_form_words(1000, true), "one thousand plus zero hundreds plus zero tens plus zero ones");
+    assert_eq!(
+        expanded_form_words(50_020, true),
+        "fifty thousand plus zero thousands plus zero hundreds plus two tens plus zero ones"
+    );
+    assert_eq!(expanded_form_words(2_000_000, true).split(" plus ").nth(1), Some("zero hundred thousands"));
+    assert_eq!(expanded_form_words(u128::MAX, false).split(" plus ").next(), Some("three hundred undecillion"));
+}
+
+#[test]
+fn british_nums() {
+    let test = |n, expected: &str| assert_eq!(u128_to_words_british(n), expected, "{}", n);
+    test(0, "zero");
+    test(7, "seven");
+    test(100, "one hundred");
+    test(101, "one hundred and one");
+    test(123, "one hundred and twenty-three");
+    test(1_000, "one thousand");
+    test(1_001, "one thousand and one");
+    test(1_099, "one thousand and ninety-nine");
+    test(1_100, "one thousand one hundred");
+    test(2_305, "two thousand three hundred and five");
+    test(1_000_050, "one million and fifty");
+    test(123_456_789, "one hundred and twenty-three million four hundred and fifty-six thousand seven hundred and eighty-nine");
+    test(5_000_120_000, "five billion one hundred and twenty thousand");
+
+    // without the "and"s, the result is the same as the American one
+    for n in [0, 1, 99, 100, 101, 1_001, 2_305, 123_456_789, u128::MAX] {
+        let words = u128_to_words_british(n);
+        let american = words.split(' ').filter(|word| *word != "and").collect::<Vec<_>>().join(" ");
+        assert_eq!(american, u128_to_words(n));
+    }
+
+    assert_eq!(str_to_words_british("2305"), Ok("two thousand three hundred and five".to_string()));
+    assert_eq!(str_to_words_british("-101.5"), Ok("negative one hundred and one point five".to_string()));
+    assert_eq!(str_to_words_british(".25"), Ok("point two five".to_string()));
+    assert_eq!(str_to_words_british("1a"), Err(StrConversionError::InvalidString));
+    for (input, expected) in get_inputs_and_expected_outputs("str_nums_ok.csv") {
+        let words = str_to_words_british(&input).unwrap();
+        assert_eq!(words.split(' ').filter(|word| *word != "and").collect::<Vec<_>>().join(" "), expected);
+    }
+}
+
+#[test]
+fn capitalized_first_letters() {
+    assert_eq!(capitalize_first(&u8_to_words(0)), "Zero");
+    assert_eq!(capitalize_first(&u16_to_words(21)), "Twenty-one");
+    assert_eq!(capitalize_first(&i64_to_words(-2)), "Negative two");
+    assert_eq!(capitalize_first(&u128_to_words(1_000_142)), "One million one hundred forty-two");
+    assert_eq!(capitalize_first(&str_to_words(".5").unwrap()), "Point five");
+    assert_eq!(capitalize_first("\"forty\""), "\"Forty\"");
+    assert_eq!(capitalize_first("Already"), "Already");
+    assert_eq!(capitalize_first(""), "");
+    assert_eq!(capitalize_first("42"), "42");
+}
+
+#[test]
+fn title_cased_words() {
+    assert_eq!(to_title_case(&u128_to_ord_words(21)), "Twenty-First");
+    assert_eq!(to_title_case(&u16_to_words(142)), "One Hundred Forty-Two");
+    assert_eq!(to_title_case("negative one hundred twenty-first point five"), "Negative One Hundred Twenty-First Point Five");
+    assert_eq!(to_title_case(&u128_to_words(0)), "Zero");
+    assert_eq!(to_title_case(&u32_to_ord_words(1_000_000)), "One Millionth");
+    assert_eq!(to_title_case(""), "");
+    assert_eq!(to_title_case("Already Title"), "Already Title");
+}
+
+#[test]
+fn words_with_options() {
+    let default = WordsOptions::default();
+    assert_eq!(default, WordsOptions::new());
+    for n in [0, 1, 21, 101, 2_005, 123_456_789, u128::MAX] {
+        assert_eq!(default.u128_to_words(n), u128_to_words(n));
+    }
+    for n in [0, -1, 21, -101, i128::MIN, i128::MAX] {
+        assert_eq!(default.i128_to_words(n), i128_to_words(n));
+    }
+    for (input, expected) in get_inputs_and_expected_outputs("str_nums_ok.csv") {
+        assert_eq!(default.str_to_words(&input), Ok(expected));
+    }
+    for (input, _) in get_inputs_and_expected_outputs("str_nums_err_invalid.csv") {
+        assert_eq!(default.str_to_words(&input), Err(StrConversionError::InvalidString));
+    }
+
+    let british = WordsOptions::new().british(true);
+    assert_eq!(british.u128_to_words(2_005), u128_to_words_british(2_005));
+    assert_eq!(british.i128_to_words(-101), "negative one hundred and one");
+
+    let capitalized = WordsOptions::new().capitalize(true);
+    assert_eq!(capitalized.u128_to_words(0), "Zero");
+    assert_eq!(capitalized.i128_to_words(-21), "Negative twenty-one");
+    assert_eq!(capitalized.str_to_words(".5"), Ok("Point five".to_string()));
+
+    let minus = WordsOptions::new().negative_word("minus");
+    assert_eq!(minus.i128_to_words(-5), "minus five");
+    assert_eq!(minus.i128_to_words(5), "five");
+    assert_eq!(minus.str_to_words("-0.5"), Ok("minus zero point five".to_string()));
+    assert_eq!(minus.str_to_words("-.5"), Ok("minus point five".to_string()));
+
+    let runtime_word = ["min", "us"].concat();
+    assert_eq!(WordsOptions::new().negative_word(runtime_word), minus);
+
+    let all = minus.clone().british(true).capitalize(true);
+    assert_eq!(all.i128_to_words(-1_000_101), "Minus one million one hundred and one");
+    assert_eq!(all.str_to_words("-105.25"), Ok("Minus one hundred and five point two five".to_string()));
+    assert_ne!(all, minus);
+}
+
+#[test]
+fn usd_amounts() {
+    let ok = |dollars, cents| to_usd_words(dollars, cents).unwrap();
+    assert_eq!(ok(1_234, 50), "one thousand two hundred thirty-four dollars and fifty cents");
+    assert_eq!(ok(0, 5), "zero dollars and five cents");
+    assert_eq!(ok(1, 1), "one dollar and one cent");
+    assert_eq!(ok(1, 0), "one dollar and zero cents");
+    assert_eq!(ok(21, 99), "twenty-one dollars and ninety-nine cents");
+    assert_eq!(to_usd_words(0, 100), Err(CurrencyConversionError::InvalidCents));
+    assert_eq!(to_usd_words(0, 255), Err(CurrencyConversionError::InvalidCents));
+
+    assert_eq!(to_usd_words_with(1, 0, ZeroCents::Omit), Ok("one dollar".to_string()));
+    assert_eq!(to_usd_words_with(0, 0, ZeroCents::Omit), Ok("zero dollars".to_string()));
+    assert_eq!(to_usd_words_with(2, 10, ZeroCents::Omit), Ok("two dollars and ten cents".to_string()));
+    assert_eq!(to_usd_words_with(2, 0, ZeroCents::Spell), Ok("two dollars and zero cents".to_string()));
+    assert_eq!(to_usd_words_with(2, 100, ZeroCents::Omit), Err(CurrencyConversionError::InvalidCents));
+
+    assert_eq!(CurrencyConversionError::InvalidCents.to_string(), "cents must be between 0 and 99");
+    assert_eq!(
+        ConversionError::from(CurrencyConversionError::InvalidCents),
+        ConversionError::Currency(CurrencyConversionError::InvalidCents)
+    );
+}
+
+#[test]
+fn currency_amounts() {
+    assert_eq!(currency_to_words(2, 99, &Currency::EUR), "two euros and ninety-nine cents");
+    assert_eq!(currency_to_words(1, 1, &Currency::EUR), "one euro and one cent");
+    assert_eq!(currency_to_words(0, 0, &Currency::EUR), "zero euros and zero cents");
+    assert_eq!(currency_to_words(1, 50, &Currency::GBP), "one pound and fifty pence");
+    assert_eq!(currency_to_words(3, 1, &Currency::GBP), "three pounds and one penny");
+    assert_eq!(currency_to_words(1_234, 50, &Currency::USD), to_usd_words(1_234, 50).unwrap());
+
+    let custom = Currency { major_singular: "crown", major_plural: "crowns", minor_singular: "öre", minor_plural: "öre" };
+    assert_eq!(currency_to_words(21, 11, &custom), "twenty-one crowns and eleven öre");
+}
+
+#[test]
+fn nums_parsed_from_words() {
+    for (input, words) in get_inputs_and_expected_outputs("u128_nums.csv") {
+        let n: u128 = input.parse().unwrap();
+        assert_eq!(words_to_u128(&words), Ok(n), "{}", words);
+        assert_eq!(words_to_u128(&u128_to_words_british(n)), Ok(n), "{}", n);
+        assert_eq!(words_to_u128(&to_title_case(&words)), Ok(n), "{}", n);
+    }
+    for n in (0..=1_000_000).step_by(7).chain([10u128.pow(36), 10u128.pow(38)]) {
+        assert_eq!(words_to_u128(&u128_to_words(n)), Ok(n), "{}", n);
+    }
+
+    assert_eq!(words_to_u128("  twenty-one\tthousand\n"), Ok(21_000));
+    assert_eq!(words_to_u128("one million and five"), Ok(1_000_005));
+    assert_eq!(words_to_u128("one hundred and one thousand and one"), Ok(101_001));
+    assert_eq!(words_to_u128("twenty one"), Ok(21));
+    assert_eq!(words_to_u128("ninety nine thousand nine hundred and twenty five"), Ok(99_925));
+    for n in (0..=100_000).step_by(13) {
+        assert_eq!(words_to_u128(&WordsOptions::new().hyphenate(false).u128_to_words(n)), Ok(n), "{}", n);
+    }
+
+    for invalid_word in ["one hundred potatoes", "twenty-ten", "ten-one", "twenty-", "-one", "one,", "1", "minus one", "twenty--one"] {
+        assert_eq!(words_to_u128(invalid_word), Err(WordsParseError::InvalidWord), "{}", invalid_word);
+    }
+    for invalid_sequence in [
+        "", "  ", "and", "hundred", "thousand", "zero zero", "zero one", "one zero", "one zero thousand",
+        "five six", "five twenty", "twenty ten", "ten hundred", "one hundred hundred", "one hundred and",
+        "one and one", "and one", "one thousand and", "one thousand and and one", "one thousand thousand",
+        "one thousand million", "one million two million", "one thousand one hundred hundred",
+    ] {
+        assert_eq!(words_to_u128(invalid_sequence), Err(WordsParseError::InvalidSequence), "{}", invalid_sequence);
+    }
+
+    assert_eq!(WordsParseError::TooLarge.to_string(), StrConversionError::TooLarge.to_string());
+    assert_eq!(ConversionError::from(WordsParseError::InvalidWord).message(), WordsParseError::InvalidWord.message());
+}
+
+#[test]
+fn signed_nums_parsed_from_words() {
+    for (input, words) in get_inputs_and_expected_outputs("i128_nums.csv") {
+        assert_eq!(words_to_i128(&words), Ok(input.parse().unwrap()), "{}", words);
+    }
+    for n in [0, 1, -1, 21, -21, 1_000_000_007, -1_000_000_007] {
+        assert_eq!(words_to_i128(&i128_to_words(n)), Ok(n), "{}", n);
+    }
+    assert_eq!(words_to_i128("  Negative twenty one"), Ok(-21));
+    assert_eq!(words_to_i128("negative zero"), Ok(0));
+    assert_eq!(words_to_i128("negative"), Err(WordsParseError::InvalidSequence));
+    assert_eq!(words_to_i128("negative negative one"), Err(WordsParseError::InvalidWord));
+    assert_eq!(words_to_i128("minus one"), Err(WordsParseError::InvalidWord));
+}
+
+#[test]
+fn nums_parsed_from_words_at_the_bounds() {
+    for n in [u128::MAX, u128::MAX - 1, u128::MAX / 1000, 1u128 << 127] {
+        assert_eq!(words_to_u128(&u128_to_words(n)), Ok(n), "{}", n);
+        assert_eq!(words_to_u128(&u128_to_words_british(n)), Ok(n), "{}", n);
+        assert_eq!(words_to_u128(&WordsOptions::new().hyphenate(false).u128_to_words(n)), Ok(n), "{}", n);
+    }
+    for n in [i128::MAX, i128::MAX - 1, i128::MIN, i128::MIN + 1] {
+        assert_eq!(words_to_i128(&i128_to_words(n)), Ok(n), "{}", n);
+    }
+
+    // just past the bounds, including overflows in a single period before the rest is added
+    let u128_max_plus_one = u128_to_words(u128::MAX).replace("fifty-five", "fifty-six");
+    assert_eq!(words_to_u128(&u128_max_plus_one), Err(WordsParseError::TooLarge));
+    assert_eq!(words_to_u128("three hundred forty-one undecillion"), Err(WordsParseError::TooLarge));
+    assert_eq!(words_to_u128("nine hundred ninety-nine undecillion"), Err(WordsParseError::TooLarge));
+    let i128_max_plus_one = i128_to_words(i128::MAX).replace("seven hundred twenty-seven", "seven hundred twenty-eight");
+    assert_eq!(words_to_i128(&i128_max_plus_one), Err(WordsParseError::TooLarge));
+    assert_eq!(words_to_i128(&i128_to_words(i128::MIN).replace("twenty-eight", "twenty-nine")), Err(WordsParseError::TooLarge));
+    assert_eq!(words_to_i128(&u128_to_words(u128::MAX)), Err(WordsParseError::TooLarge));
+    assert_eq!(words_to_i128(&("negative ".to_string() + &u128_to_words(u128::MAX))), Err(WordsParseError::TooLarge));
+}
+
+#[test]
+fn num_strs_parsed_from_words() {
+    for (input, words) in get_inputs_and_expected_outputs("str_nums_ok.csv") {
+        if input.is_empty() {
+            continue;
+        }
+        let parsed = words_to_str(&words).unwrap();
+        assert_eq!(str_to_words(&parsed), Ok(words.clone()), "{} -> {}", input, parsed);
+    }
+
+    assert_eq!(words_to_str("one hundred twenty-three point four five six"), Ok("123.456".to_string()));
+    assert_eq!(words_to_str("point zero zero four two"), Ok(".0042".to_string()));
+    assert_eq!(words_to_str("negative point five"), Ok("-.5".to_string()));
+    assert_eq!(words_to_str("Negative One Thousand Point Zero"), Ok("-1000.0".to_string()));
+    assert_eq!(words_to_str("five point"), Ok("5.".to_string()));
+    assert_eq!(words_to_str("zero"), Ok("0".to_string()));
+    assert_eq!(words_to_str(&u128_to_words(u128::MAX)), Ok(u128::MAX.to_string()));
+
+    for invalid_word in ["five point six bananas", "bananas", "five point 6", "negative minus five"] {
+        assert_eq!(words_to_str(invalid_word), Err(WordsParseError::InvalidWord), "{}", invalid_word);
+    }
+    for invalid_sequence in [
+        "", "negative", "point", "negative negative five", "five negative", "one point twenty",
+        "one point five point five", "one point hundred", "point point", "one point negative",
+    ] {
+        assert_eq!(words_to_str(invalid_sequence), Err(WordsParseError::InvalidSequence), "{}", invalid_sequence);
+    }
+    assert_eq!(words_to_str("nine hundred undecillion point one"), Err(WordsParseError::TooLarge));
+}
+
+#[test]
+fn roman_nums() {
+    let ok = |n| u16_to_roman(n).unwrap();
+    assert_eq!(ok(1), "I");
+    assert_eq!(ok(4), "IV");
+    assert_eq!(ok(9), "IX");
+    assert_eq!(ok(14), "XIV");
+    assert_eq!(ok(40), "XL");
+    assert_eq!(ok(90), "XC");
+    assert_eq!(ok(400), "CD");
+    assert_eq!(ok(900), "CM");
+    assert_eq!(ok(1984), "MCMLXXXIV");
+    assert_eq!(ok(2024), "MMXXIV");
+    assert_eq!(ok(3999), "MMMCMXCIX");
+    assert_eq!(u16_to_roman(0), Err(RomanError::Zero));
+    assert_eq!(u16_to_roman(4000), Err(RomanError::TooLarge));
+    assert_eq!(u16_to_roman(u16::MAX), Err(RomanError::TooLarge));
+
+    assert_eq!(u16_to_roman_lowercase(1984), Ok("mcmlxxxiv".to_string()));
+    assert_eq!(u16_to_roman_lowercase(0), Err(RomanError::Zero));
+
+    // every numeral is unique and adds up to its value
+    let value = |c| match c { 'I' => 1, 'V' => 5, 'X' => 10, 'L' => 50, 'C' => 100, 'D' => 500, 'M' => 1000, _ => unreachable!() };
+    let mut seen = std::collections::HashSet::new();
+    for n in 1..=3999 {
+        let roman = ok(n);
+        let values = roman.chars().map(value).collect::<Vec<i32>>();
+        let sum: i32 = values.iter().enumerate()
+            .map(|(i, &v)| if values.get(i + 1).map_or(false, |&next| next > v) { -v } else { v })
+            .sum();
+        assert_eq!(sum, n as i32, "{}", roman);
+        assert!(seen.insert(roman));
+    }
+
+    assert_eq!(RomanError::TooLarge.to_string(), "number is too large for Roman numerals (max is 3999)");
+    assert_eq!(ConversionError::from(RomanError::Zero), ConversionError::Roman(RomanError::Zero));
+}
+
+#[test]
+fn year_words() {
+    let test = |year, expected: &str| assert_eq!(u16_to_year_words(year), expected, "{}", year);
+    test(0, "zero");
+    test(999, "nine hundred ninety-nine");
+    test(1000, "one thousand");
+    test(1001, "ten oh-one");
+    test(1066, "ten sixty-six");
+    test(1100, "eleven hundred");
+    test(1776, "seventeen seventy-six");
+    test(1900, "nineteen hundred");
+    test(1905, "nineteen oh-five");
+    test(1984, "nineteen eighty-four");
+    test(2000, "two thousand");
+    test(2009, "twenty oh-nine");
+    test(2010, "twenty ten");
+    test(2024, "twenty twenty-four");
+    test(3000, "three thousand");
+    test(9999, "ninety-nine ninety-nine");
+    test(10_000, "ten thousand");
+    test(u16::MAX, &u16_to_words(u16::MAX));
+}
+
+#[test]
+fn signed_ord_nums() {
+    for (input, expected) in get_inputs_and_expected_outputs("u128_ord_nums.csv") {
+        let n: u128 = input.parse().unwrap();
+        if let Ok(n) = i128::try_from(n) {
+            assert_eq!(i128_to_ord_words(n), expected);
+            if n != 0 {
+                assert_eq!(i128_to_ord_words(-n), "negative ".to_string() + &expected);
+            }
+        }
+    }
+    for (input, expected) in get_inputs_and_expected_outputs("u16_ord_nums.csv") {
+        let n: u16 = input.parse().unwrap();
+        if let Ok(n) = i16::try_from(n) {
+            assert_eq!(i16_to_ord_words(n), expected);
+            assert_eq!(i32_to_ord_words(-(n as i32)), if n == 0 { expected } else { "negative ".to_string() + &expected });
+        }
+    }
+
+    assert_eq!(i8_to_ord_words(0), "zeroth");
+    assert_eq!(i8_to_ord_words(-42), "negative forty-second");
+    assert_eq!(i8_to_ord_words(i8::MIN), "negative one hundred twenty-eighth");
+    assert_eq!(i8_to_ord_words(i8::MAX), "one hundred twenty-seventh");
+    assert_eq!(i16_to_ord_words(i16::MIN), "negative ".to_string() + &u16_to_ord_words(32_768));
+    assert_eq!(i32_to_ord_words(i32::MIN), "negative ".to_string() + &u32_to_ord_words(1 << 31));
+    assert_eq!(i64_to_ord_words(i64::MIN), "negative ".to_string() + &u64_to_ord_words(1 << 63));
+    assert_eq!(i128_to_ord_words(i128::MIN), "negative ".to_string() + &u128_to_ord_words(1 << 127));
+    assert_eq!(isize_to_ord_words(-1), "negative first");
+}
+
+#[test]
+fn nums_represented_by_str_as_fractions() {
+    let ok = |s: &str| str_to_fraction_words(s).unwrap();
+    assert_eq!(ok("0.5"), "one half");
+    assert_eq!(ok(".50"), "one half");
+    assert_eq!(ok("2.5"), "two and a half");
+    assert_eq!(ok("-2.5"), "negative two and a half");
+    assert_eq!(ok("-0.5"), "negative one half");
+    assert_eq!(ok("1.25"), "one and a quarter");
+    assert_eq!(ok("0.75"), "three quarters");
+    assert_eq!(ok("3.2"), "three and a fifth");
+    assert_eq!(ok("3.4"), "three and two fifths");
+    assert_eq!(ok("0.6"), "three fifths");
+    assert_eq!(ok("0.8"), "four fifths");
+    assert_eq!(ok("0.125"), "one eighth");
+    assert_eq!(ok("1.125"), "one and an eighth");
+    assert_eq!(ok("4.375"), "four and three eighths");
+    assert_eq!(ok("0.625"), "five eighths");
+    assert_eq!(ok("0.875000"), "seven eighths");
+    assert_eq!(ok("0.33"), "one third");
+    assert_eq!(ok("1.3333"), "one and a third");
+    assert_eq!(ok("0.67"), "two thirds");
+    assert_eq!(ok("5.666"), "five and two thirds");
+
+    // no clean fraction
+    assert_eq!(ok("0.3"), "zero point three");
+    assert_eq!(ok("0.68"), "zero point six eight");
+    assert_eq!(ok("0.35"), "zero point three five");
+    assert_eq!(ok("2.0"), "two point zero");
+    assert_eq!(ok("2."), "two point");
+    assert_eq!(ok("42"), "forty-two");
+    assert_eq!(str_to_fraction_words("2.5.5"), Err(StrConversionError::InvalidString));
+    assert_eq!(str_to_fraction_words("340282366920938463463374607431768211456.5"), Err(StrConversionError::TooLarge));
+}
+
+#[test]
+fn nums_in_scientific_notation() {
+    test_result_func("str_nums_scientific.csv",
+        |i| i.to_string(),
+        |o| Ok(o.to_string()),
+        |x| str_to_words(&x));
+
+    for input in ["1e", "e5", "1e1.5", "1e+", "-e2", "1.2.3e1"] {
+        assert_eq!(str_to_words(input), Err(StrConversionError::InvalidString), "{}", input);
+    }
+    for input in ["3.5e38", "1e99999"] {
+        assert_eq!(str_to_words(input), Err(StrConversionError::TooLarge), "{}", input);
+    }
+}
+
+#[test]
+fn signed_nums_with_minus() {
+    let minus = WordsOptions::new().negative_word("minus");
+    assert_eq!(minus.i8_to_words(i8::MIN), "minus one hundred twenty-eight");
+    assert_eq!(minus.i16_to_words(-5), "minus five");
+    assert_eq!(minus.i32_to_words(-5), "minus five");
+    assert_eq!(minus.i64_to_words(-2_000_001), "minus two million one");
+    assert_eq!(minus.isize_to_words(42), "forty-two");
+    assert_eq!(minus.str_to_words("-5"), Ok("minus five".to_string()));
+
+    let default = WordsOptions::new();
+    for n in [0, -1, 21, -101, i32::MIN, i32::MAX] {
+        assert_eq!(default.i32_to_words(n), i32_to_words(n));
+    }
+}
+
+#[test]
+fn nums_written_into_string() {
+    for (input, expected) in get_inputs_and_expected_outputs("u128_nums.csv") {
+        let mut words = "unchanged ".to_string();
+        write_u128_words(input.parse().unwrap(), &mut words);
+        assert_eq!(words, "unchanged ".to_string() + &expected);
+    }
+
+    let mut sentence = String::new();
+    write_u8_words(0, &mut sentence);
+    sentence.push_str(", ");
+    write_u16_words(1_000, &mut sentence);
+    sentence.push_str(", ");
+    write_u64_words(21, &mut sentence);
+    assert_eq!(sentence, "zero, one thousand, twenty-one");
+}
+
+#[test]
+fn nums_words_iter() {
+    for (input, expected) in get_inputs_and_expected_outputs("u128_nums.csv") {
+        let words: Vec<_> = u128_words_iter(input.parse().unwrap()).collect();
+        assert_eq!(words.join(" "), expected);
+    }
+
+    let mut iter = u128_words_iter(20_021);
+    assert!(matches!(iter.next(), Some(std::borrow::Cow::Borrowed("twenty"))));
+    assert!(matches!(iter.next(), Some(std::borrow::Cow::Borrowed("thousand"))));
+    assert!(matches!(iter.next(), Some(std::borrow::Cow::Owned(word)) if word == "twenty-one"));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn words_through_traits() {
+    fn both<T: ToWords + ToOrdWords>(x: T) -> (String, String) {
+        (x.to_words(), x.to_ord_words())
+    }
+
+    assert_eq!(both(0u8), ("zero".to_string(), "zeroth".to_string()));
+    assert_eq!(both(12u16), ("twelve".to_string(), "twelfth".to_string()));
+    assert_eq!(both(-21i32), ("negative twenty-one".to_string(), "negative twenty-first".to_string()));
+    assert_eq!(u128::MAX.to_words(), u128_to_words(u128::MAX));
+    assert_eq!(i128::MIN.to_ord_words(), i128_to_ord_words(i128::MIN));
+    assert_eq!(usize::MAX.to_words(), usize_to_words(usize::MAX));
+    assert_eq!(isize::MIN.to_ord_words(), isize_to_ord_words(isize::MIN));
+    assert_eq!(u64::MAX.to_ord_words(), u64_to_ord_words(u64::MAX));
+}
+
+#[test]
+fn nums_in_indian_numbering() {
+    test_func("u128_nums_indian.csv",
+        |i| i.parse().unwrap(),
+        |o| o.to_string(),
+        u128_to_words_indian);
+}
+
+#[test]
+fn nums_in_long_scale() {
+    assert_eq!(u128_to_words_long_scale(0), "zero");
+    assert_eq!(u128_to_words_long_scale(999_999_999), u128_to_words(999_999_999));
+    assert_eq!(u128_to_words_long_scale(1_000_000_000), "one milliard");
+    assert_eq!(u128_to_words_long_scale(999_999_999_999),
+        "nine hundred ninety-nine milliard nine hundred ninety-nine million nine hundred ninety-nine thousand nine hundred ninety-nine");
+    assert_eq!(u128_to_words_long_scale(1_000_000_000_000), "one billion");
+    assert_eq!(u128_to_words_long_scale(1_001_000_000_000), "one billion one milliard");
+    assert_eq!(u128_to_words_long_scale(10u128.pow(15)), "one billiard");
+    assert_eq!(u128_to_words_long_scale(10u128.pow(36)), "one sextillion");
+    assert_eq!(
+        u128_to_words_long_scale(u128::MAX),
+        "three hundred forty sextillion two hundred eighty-two quintilliard three hundred sixty-six quintillion \
+        nine hundred twenty quadrilliard nine hundred thirty-eight quadrillion four hundred sixty-three trilliard \
+        four hundred sixty-three trillion three hundred seventy-four billiard six hundred seven billion \
+        four hundred thirty-one milliard seven hundred sixty-eight million two hundred eleven thousand four hundred fifty-five"
+    );
+}
+
+#[test]
+fn nums_with_point_word() {
+    let dot = WordsOptions::new().point_word("dot");
+    assert_eq!(dot.str_to_words("1.5"), Ok("one dot five".to_string()));
+    assert_eq!(dot.str_to_words("-0.05"), Ok("negative zero dot zero five".to_string()));
+    assert_eq!(dot.str_to_words(".5"), Ok("dot five".to_string()));
+    assert_eq!(dot.str_to_words("2."), Ok("two dot".to_string()));
+    assert_eq!(dot.str_to_words("42"), Ok("forty-two".to_string()));
+
+    let comma = WordsOptions::new().point_word("comma").capitalize(true);
+    assert_eq!(comma.str_to_words(".25"), Ok("Comma two five".to_string()));
+}
+
+#[test]
+fn digits_read_like_phone_numbers() {
+    assert_eq!(str_digits_to_words_phone("0800"), Ok("oh eight oh oh".to_string()));
+    assert_eq!(str_digits_to_words_phone("5550123"), Ok("five five five oh one two three".to_string()));
+    assert_eq!(str_digits_to_words_phone(""), Ok("".to_string()));
+    assert_eq!(str_digits_to_words_phone("0x0"), Err(DigitConversionError::InvalidCharacter));
+    assert_eq!(str_digits_to_words("0800"), Ok("zero eight zero zero".to_string()));
+}
+
+#[test]
+fn digits_read_with_double_and_triple() {
+    let ok = |digits| str_digits_to_words_phone_compressed(digits).unwrap();
+    assert_eq!(ok(""), "");
+    assert_eq!(ok("7"), "seven");
+    assert_eq!(ok("77"), "double seven");
+    assert_eq!(ok("777"), "triple seven");
+    assert_eq!(ok("7777"), "seven seven seven seven");
+    assert_eq!(ok("7700"), "double seven double oh");
+    assert_eq!(ok("7000"), "seven triple oh");
+    assert_eq!(ok("1221"), "one double two one");
+    assert_eq!(ok("0117 496 0018".replace(' ', "").as_str()), "oh double one seven four nine six double oh one eight");
+    assert_eq!(str_digits_to_words_phone_compressed("77a"), Err(DigitConversionError::InvalidCharacter));
+    assert_eq!(str_digits_to_words_phone_compressed("７"), Err(DigitConversionError::InvalidCharacter));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn errors_as_std_errors() {
+    fn spell_both(digits: &str, number: &str) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(str_digits_to_words(digits)? + ", " + &str_to_words(number)?)
+    }
+
+    assert_eq!(spell_both("12", "3.5").unwrap(), "one two, three point five");
+    assert_eq!(spell_both("1a", "3").unwrap_err().to_string(), DigitConversionError::InvalidCharacter.message());
+    assert_eq!(spell_both("1", "3..5").unwrap_err().to_string(), StrConversionError::InvalidString.message());
+
+    let errors: Vec<Box<dyn std::error::Error>> = vec![
+        Box::new(FloatConversionError::NotFinite),
+        Box::new(ConversionError::from(StrConversionError::TooLarge)),
+        Box::new(RomanError::Zero),
+        Box::new(WordsParseError::InvalidWord),
+    ];
+    for error in errors {
+        assert!(!error.to_string().is_empty());
+    }
+}
+
+#[test]
+fn ord_nums_with_suffix() {
+    let cases = [
+        (0, "0th"), (1, "1st"), (2, "2nd"), (3, "3rd"), (4, "4th"), (10, "10th"),
+        (11, "11th"), (12, "12th"), (13, "13th"), (21, "21st"), (22, "22nd"), (23, "23rd"),
+        (101, "101st"), (111, "111th"), (112, "112th"), (113, "113th"), (1_012, "1012th"), (1_021, "1021st"),
+    ];
+    for (n, expected) in cases {
+        assert_eq!(u128_to_ord_suffix(n), expected);
+    }
+    assert_eq!(u128_to_ord_suffix(u128::MAX), u128::MAX.to_string() + "th");
+}
+
+#[test]
+fn nums_beyond_u128() {
+    let names = [
+        "duodecillion", "tredecillion", "quattuordecillion", "quindecillion", "sexdecillion",
+        "septendecillion", "octodecillion", "novemdecillion", "vigintillion",
+    ];
+    assert_eq!(MAX_SUPPORTED_PERIODS, 12 + names.len());
+    for (i, name) in names.iter().enumerate() {
+        let exponent = 39 + 3 * i;
+        let boundary = "1".to_string() + &"0".repeat(exponent);
+        assert_eq!(str_to_words_large(&boundary), Ok("one ".to_string() + name), "10^{}", exponent);
+
+        let below = "9".repeat(exponent);
+        let expected = str_to_words_large(&below[3..]).unwrap();
+        let expected = format!("nine hundred ninety-nine {} {}", PERIODS[exponent / 3 - 2], expected);
+        assert_eq!(str_to_words_large(&below), Ok(expected), "10^{} - 1", exponent);
+    }
+
+    assert_eq!(
+        str_to_words_large("-1000000000000000000000000000000000000000021.5"),
+        Ok("negative one tredecillion twenty-one point five".to_string())
+    );
+    assert_eq!(str_to_words_large("1,000,000,000,000,000,000,000,000,000,000,000,000,000"), Ok("one duodecillion".to_string()));
+    assert_eq!(str_to_words_large(&"9".repeat(66)).map(|words| words.starts_with("nine hundred ninety-nine vigintillion")), Ok(true));
+    assert_eq!(str_to_words_large(&("0".to_string() + &"9".repeat(66))), str_to_words_large(&"9".repeat(66)));
+    assert_eq!(str_to_words_large(&"9".repeat(67)), Err(StrConversionError::TooLarge));
+    assert_eq!(str_to_words_large("1e99999"), Err(StrConversionError::TooLarge));
+    assert_eq!(str_to_words_large("1e40.5"), Err(StrConversionError::InvalidString));
+    for (input, expected) in get_inputs_and_expected_outputs("str_nums_ok.csv") {
+        assert_eq!(str_to_words_large(&input), Ok(expected));
+    }
+
+    // the u128 conversions are unaffected by the longer table
+    assert_eq!(u128_to_words(u128::MAX), str_to_words(&u128::MAX.to_string()).unwrap());
+    assert_eq!(words_to_u128("one duodecillion"), Err(WordsParseError::TooLarge));
+}
+
+#[test]
+fn words_parsed_to_f64() {
+    for (input, _) in get_inputs_and_expected_outputs("f64_nums_ok.csv") {
+        let float = input.parse::<f64>().unwrap();
+        let words = f64_to_words(float).unwrap();
+        let parsed = words_to_f64(&words).unwrap();
+        assert!(parsed == float || (parsed == 0.0 && float == 0.0), "{} -> {} -> {}", input, words, parsed);
+    }
+
+    assert_eq!(words_to_f64("zero"), Ok(0.0));
+    assert_eq!(words_to_f64("Point Two Five"), Ok(0.25));
+    assert_eq!(words_to_f64("one point zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero zero one"), Ok(1.0));
+    assert_eq!(words_to_f64(""), Err(WordsParseError::InvalidSequence));
+    assert_eq!(words_to_f64("negative"), Err(WordsParseError::InvalidSequence));
+    assert_eq!(words_to_f64("one point two point three"), Err(WordsParseError::InvalidSequence));
+    assert_eq!(words_to_f64("forty-two point four twelve"), Err(WordsParseError::InvalidSequence));
+    assert_eq!(words_to_f64("forty-two dot four"), Err(WordsParseError::InvalidWord));
+}
+
+#[test]
+fn nums_displayed_as_words() {
+    for (input, expected) in get_inputs_and_expected_outputs("u128_nums.csv") {
+        assert_eq!(Words(input.parse::<u128>().unwrap()).to_string(), expected);
+    }
+    for (input, expected) in get_inputs_and_expected_outputs("i128_nums.csv") {
+        assert_eq!(Words(input.parse::<i128>().unwrap()).to_string(), expected);
+    }
+    for (input, expected) in get_inputs_and_expected_outputs("u128_ord_nums.csv") {
+        assert_eq!(OrdWords(input.parse::<u128>().unwrap()).to_string(), expected);
+    }
+
+    assert_eq!(Words(0u8).to_string(), "zero");
+    assert_eq!(Words(i8::MIN).to_string(), "negative one hundred twenty-eight");
+    assert_eq!(Words(usize::MAX).to_string(), usize_to_words(usize::MAX));
+    assert_eq!(format!("{}/{}", Words(1u16), OrdWords(-3i32)), "one/negative third");
+
+    let mut out = String::new();
+    use core::fmt::Write;
+    writeln!(out, "{} and {}", Words(20u64), OrdWords(1u64)).unwrap();
+    assert_eq!(out, "twenty and first\n");
+
+    assert_eq!(format!("{:>30}", Words(5u8)), format!("{:>30}", "five"));
+    assert_eq!(format!("{:*^10}", Words(-5i8)), "negative five");
+    assert_eq!(format!("{:*^17}", Words(-5i8)), "**negative five**");
+    assert_eq!(format!("{:<6}|", Words(10u128)), "ten   |");
+    assert_eq!(format!("{:.5}", Words(1_000u32)), "one t");
+    assert_eq!(format!("{:>8}", OrdWords(2u8)), "  second");
+}
+
+#[test]
+fn percentages() {
+    let ok = |string| str_percent_to_words(string).unwrap();
+    assert_eq!(ok("12.5%"), "twelve point five percent");
+    assert_eq!(ok("100%"), "one hundred percent");
+    assert_eq!(ok("0%"), "zero percent");
+    assert_eq!(ok("-0.25%"), "negative zero point two five percent");
+    assert_eq!(ok(".5%"), "point five percent");
+    assert_eq!(ok("1,000%"), "one thousand percent");
+
+    for input in ["%", "12.5", "12.5%%", "12.5 %", "%12.5", "-%", "a%"] {
+        assert_eq!(str_percent_to_words(input), Err(StrConversionError::InvalidString), "{}", input);
+    }
+    assert_eq!(str_percent_to_words("340282366920938463463374607431768211456%"), Err(StrConversionError::TooLarge));
+}
+
+#[test]
+fn nums_with_trimmed_fractional_zeros() {
+    let trim = WordsOptions::new().trim_fractional_zeros(true);
+    assert_eq!(trim.str_to_words("34.500"), Ok("thirty-four point five".to_string()));
+    assert_eq!(trim.str_to_words("0.05"), Ok("zero point zero five".to_string()));
+    assert_eq!(trim.str_to_words("1.0050"), Ok("one point zero zero five".to_string()));
+    assert_eq!(trim.str_to_words("5.000"), Ok("five".to_string()));
+    assert_eq!(trim.str_to_words("5."), Ok("five".to_string()));
+    assert_eq!(trim.str_to_words("-.000"), Ok("zero".to_string()));
+    assert_eq!(trim.str_to_words(".50"), Ok("point five".to_string()));
+    assert_eq!(trim.str_to_words("100"), Ok("one hundred".to_string()));
+
+    let keep = WordsOptions::new();
+    assert_eq!(keep.str_to_words("34.500"), Ok("thirty-four point five zero zero".to_string()));
+    assert_eq!(keep.str_to_words("5."), Ok("five point".to_string()));
+}
+
+#[test]
+fn nums_converted_in_batches() {
+    let inputs_and_outputs = get_inputs_and_expected_outputs("u128_nums.csv");
+    let nums = inputs_and_outputs.iter().map(|(input, _)| input.parse().unwrap()).collect::<Vec<u128>>();
+    let expected = inputs_and_outputs.into_iter().map(|(_, output)| output).collect::<Vec<_>>();
+    assert_eq!(u128_slice_to_words(&nums), expected);
+
+    let mut words = vec!["stale".to_string(); 3];
+    u128_slice_write_words(&nums, &mut words);
+    assert_eq!(words, expected);
+    u128_slice_write_words(&[5, 0], &mut words);
+    assert_eq!(words, vec!["five", "zero"]);
+    u128_slice_write_words(&[], &mut words);
+    assert!(words.is_empty());
+}
+
+#[test]
+fn nums_with_lone_sign_or_point() {
+    assert_eq!(str_to_words("-.5"), Ok("negative point five".to_string()));
+    assert_eq!(str_to_words("-0.5"), Ok("negative zero point five".to_string()));
+    assert_eq!(str_to_words_parts("-.5"), Ok(("negative".to_string(), "point".to_string(), "five".to_string())));
+    assert_eq!(WordsOptions::new().str_to_words("-.5"), str_to_words("-.5"));
+    for input in ["-", "-.", "-..", ".", "..", "--", "-e1"] {
+        assert_eq!(str_to_words(input), Err(StrConversionError::InvalidString), "{}", input);
+    }
+}
+
+#[test]
+fn day_ordinals_with_article() {
+    for day in 1..=31 {
+        assert_eq!(u8_to_day_ordinal(day), Ok("the ".to_string() + &u8_to_ord_words(day)));
+    }
+    assert_eq!(u8_to_day_ordinal(11), Ok("the eleventh".to_string()));
+    assert_eq!(u8_to_day_ordinal(30), Ok("the thirtieth".to_string()));
+    for day in [0, 32, u8::MAX] {
+        assert_eq!(u8_to_day_ordinal(day), Err(DayConversionError::OutOfRange));
+    }
+}
+
+#[test]
+fn nums_split_into_word_groups() {
+    for (input, expected) in get_inputs_and_expected_outputs("u128_nums.csv") {
+        assert_eq!(u128_to_word_groups(input.parse().unwrap()).join(" "), expected);
+    }
+
+    assert_eq!(u128_to_word_groups(0), vec!["zero"]);
+    assert_eq!(u128_to_word_groups(1_000), vec!["one thousand"]);
+    assert_eq!(u128_to_word_groups(1_000_000_021), vec!["one billion", "twenty-one"]);
+    assert_eq!(u128_to_word_groups(u128::MAX).len(), 13);
+}
+
+#[test]
+fn nums_without_hyphens() {
+    let spaced = WordsOptions::new().hyphenate(false);
+    for (input, expected) in get_inputs_and_expected_outputs("u128_nums.csv") {
+        assert_eq!(spaced.u128_to_words(input.parse().unwrap()), expected.replace('-', " "));
+    }
+    for (input, expected) in get_inputs_and_expected_outputs("u128_ord_nums.csv") {
+        assert_eq!(spaced.u128_to_ord_words(input.parse().unwrap()), expected.replace('-', " "));
+        assert_eq!(WordsOptions::new().u128_to_ord_words(input.parse().unwrap()), expected);
+    }
+
+    assert_eq!(spaced.u128_to_words(21), "twenty one");
+    assert_eq!(spaced.u128_to_ord_words(21), "twenty first");
+    assert_eq!(spaced.u128_to_ord_words(90), "ninetieth");
+    assert_eq!(spaced.i32_to_words(-99), "negative ninety nine");
+    assert_eq!(spaced.str_to_words("42.5"), Ok("forty two point five".to_string()));
+
+    let british = spaced.british(true).capitalize(true);
+    assert_eq!(british.u128_to_words(2_021), "Two thousand and twenty one");
+    assert_eq!(british.u128_to_ord_words(101), "One hundred and first");
+    assert_eq!(u128_to_words(21), "twenty-one");
+}
+
+#[test]
+fn const_word_lookups() {
+    const BELOW_20: [&str; 20] = {
+        let mut words = [""; 20];
+        let mut n = 0;
+        while n < 20 {
+            words[n] = word_below_20(n as u8);
+            n += 1;
+        }
+        words
+    };
+    for (n, word) in BELOW_20.iter().enumerate() {
+        assert_eq!(*word, u8_to_words(n as u8));
+    }
+    for tens in 0..10 {
+        assert_eq!(multiple_of_ten(tens), u8_to_words(tens * 10));
+    }
+}
+
+#[test]
+#[should_panic]
+fn const_word_lookup_out_of_range() {
+    word_below_20(20);
+}
+
+#[test]
+fn approximate_nums() {
+    assert_eq!(u128_to_approx_words(0, 1), "zero");
+    assert_eq!(u128_to_approx_words(7, 1), "seven");
+    assert_eq!(u128_to_approx_words(15, 1), "about twenty");
+    assert_eq!(u128_to_approx_words(14, 1), "about ten");
+    assert_eq!(u128_to_approx_words(14, 5), "fourteen");
+    assert_eq!(u128_to_approx_words(2_345_678, 1), "about two million");
+    assert_eq!(u128_to_approx_words(2_345_678, 3), "about two million three hundred fifty thousand");
+    assert_eq!(u128_to_approx_words(2_000_000, 1), "two million");
+    assert_eq!(u128_to_approx_words(999_999, 1), "about one million");
+    assert_eq!(u128_to_approx_words(999_499, 3), "about nine hundred ninety-nine thousand");
+    assert_eq!(u128_to_approx_words(999_500, 3), "about one million");
+    assert_eq!(u128_to_approx_words(u128::MAX, 1), "about three hundred undecillion");
+    assert_eq!(u128_to_approx_words(u128::MAX, 39), u128_to_words(u128::MAX));
+    assert_eq!(u128_to_approx_words(u128::MAX, 255), u128_to_words(u128::MAX));
+    // rounding up would overflow
+    assert_eq!(
+        u128_to_approx_words(u128::MAX, 38),
+        "about ".to_string() + &u128_to_words(u128::MAX / 10 * 10)
+    );
+}
+
+#[test]
+fn approximate_nums_without_sig_figs() {
+    assert_eq!(u128_to_approx_words(5, 0), "five");
+    assert_eq!(u128_to_approx_words(15, 0), u128_to_approx_words(15, 1));
+    assert_eq!(u128_to_approx_words(u128::MAX, 0), u128_to_approx_words(u128::MAX, 1));
+}
+
+#[test]
+fn ord_words_parsed_to_u128() {
+    for (input, expected) in get_inputs_and_expected_outputs("u128_ord_nums.csv") {
+        assert_eq!(words_to_ord_u128(&expected), Ok(input.parse().unwrap()), "{}", expected);
+    }
+    for n in (0..=1_000).chain([1_000_000, 1_000_012, u128::MAX]) {
+        assert_eq!(words_to_ord_u128(&u128_to_ord_words(n)), Ok(n));
+    }
+
+    assert_eq!(words_to_ord_u128("Twenty-First"), Ok(21));
+    assert_eq!(words_to_ord_u128("two thousand and fifth"), Ok(2_005));
+    assert_eq!(words_to_ord_u128("zeroth"), Ok(0));
+    for input in ["twentyth", "oneth", "twenty-oneth", "first hundred", "twenty-one", "tenieth", "th"] {
+        assert_eq!(words_to_ord_u128(input), Err(WordsParseError::InvalidWord), "{}", input);
+    }
+    assert_eq!(words_to_ord_u128(""), Err(WordsParseError::InvalidSequence));
+    assert_eq!(words_to_ord_u128("first first"), Err(WordsParseError::InvalidWord));
+    assert_eq!(words_to_ord_u128("thousand first"), Err(WordsParseError::InvalidSequence));
+    assert_eq!(words_to_ord_u128("nine hundred undecillionth"), Err(WordsParseError::TooLarge));
+}
+
+#[test]
+fn check_words() {
+    assert_eq!(to_check_words(123, 50).unwrap(), "one hundred twenty-three and 50/100 dollars");
+    assert_eq!(to_check_words(0, 99).unwrap(), "zero and 99/100 dollars");
+    assert_eq!(to_check_words(1_000_000, 1).unwrap(), "one million and 01/100 dollars");
+    assert_eq!(to_check_words(7, 0), to_check_words_with(7, 0, CheckZeroCents::Digits));
+    assert_eq!(to_check_words_with(7, 0, CheckZeroCents::No).unwrap(), "seven and no/100 dollars");
+    assert_eq!(to_check_words_with(7, 10, CheckZeroCents::No).unwrap(), "seven and 10/100 dollars");
+}
+
+#[test]
+fn check_words_with_too_many_cents() {
+    assert_eq!(to_check_words(1, 100), Err(CurrencyConversionError::InvalidCents));
+    assert_eq!(to_check_words_with(1, 100, CheckZeroCents::No), Err(CurrencyConversionError::InvalidCents));
+}
+
+#[test]
+fn str_currency_words() {
+    assert_eq!(str_to_words("1234").unwrap(), "one thousand two hundred thirty-four");
+    assert_eq!(
+        str_to_currency_words("1234.50", &Currency::USD).unwrap(),
+        "one thousand two hundred thirty-four dollars and fifty cents"
+    );
+    assert_eq!(
+        str_to_currency_words("1,101.05", &Currency::GBP).unwrap(),
+        "one thousand one hundred one pounds and five pence"
+    );
+    assert_eq!(str_to_currency_words(".01", &Currency::USD).unwrap(), "zero dollars and one cent");
+    assert_eq!(str_to_currency_words("2.", &Currency::EUR).unwrap(), "two euros and zero cents");
+    assert_eq!(str_to_currency_words("", &Currency::USD), Err(StrConversionError::InvalidString));
+    assert_eq!(str_to_currency_words(".", &Currency::USD), Err(StrConversionError::InvalidString));
+    assert_eq!(str_to_currency_words("1.234", &Currency::USD), Err(StrConversionError::InvalidString));
+    assert_eq!(str_to_currency_words("-2.50", &Currency::USD), Err(StrConversionError::InvalidString));
+}
+
+#[test]
+fn cardinal_and_ordinal_words() {
+    for n in (0..2_000u128).chain([1_000_000, 12_000_000_000_012, u128::MAX]) {
+        assert_eq!(u128_to_words_both(n), (u128_to_words(n), u128_to_ord_words(n)));
+    }
+}
+
+#[test]
+fn ordinalized_words() {
+    let cases = [
+        ("zero", "zeroth"), ("one", "first"), ("two", "second"), ("three", "third"), ("five", "fifth"),
+        ("eight", "eighth"), ("nine", "ninth"), ("twelve", "twelfth"), ("fifteen", "fifteenth"),
+        ("twenty", "twentieth"), ("ninety-nine", "ninety-ninth"), ("hundred", "hundredth"),
+        ("thousand", "thousandth"),
+    ];
+    for (cardinal, ordinal) in cases {
+        assert_eq!(ordinalize_word(cardinal), ordinal);
+    }
+}
+
+#[test]
+fn nums_without_trailing_point() {
+    let options = WordsOptions::new().trailing_point(false);
+    for (input, expected) in get_inputs_and_expected_outputs("str_nums_ok.csv") {
+        let expected = if input.ends_with('.') { expected.strip_suffix(" point").unwrap() } else { &expected };
+        assert_eq!(options.str_to_words(&input).unwrap(), expected, "input: {}", input);
+    }
+    assert_eq!(str_to_words("-1095.").unwrap(), "negative one thousand ninety-five point");
+}
+
+#[test]
+fn digit_words_of_nums() {
+    assert_eq!(u128_to_digit_words(907), "nine zero seven");
+    assert_eq!(u128_to_digit_words(u128::MAX), str_digits_to_words(&u128::MAX.to_string()).unwrap());
+    assert_eq!(u128_to_digit_words(u128::MAX).split(' ').count(), 39);
+}
+
+#[test]
+fn nums_in_locales() {
+    let cases = [
+        (Locale::American, 123, "one hundred twenty-three"),
+        (Locale::American, 1_234, "one thousand two hundred thirty-four"),
+        (Locale::American, 1_234_567, "one million two hundred thirty-four thousand five hundred sixty-seven"),
+        (Locale::BritishWithAnd, 123, "one hundred and twenty-three"),
+        (Locale::BritishWithAnd, 1_234, "one thousand two hundred and thirty-four"),
+        (Locale::BritishWithAnd, 1_234_567, "one million two hundred and thirty-four thousand five hundred and sixty-seven"),
+        (Locale::Indian, 123, "one hundred twenty-three"),
+        (Locale::Indian, 1_234, "one thousand two hundred thirty-four"),
+        (Locale::Indian, 1_234_567, "twelve lakh thirty-four thousand five hundred sixty-seven"),
+    ];
+    for (locale, n, expected) in cases {
+        assert_eq!(u128_to_words_locale(n, locale), expected, "{:?} {}", locale, n);
+    }
+}
+
+#[test]
+fn floats_with_precision() {
+    assert_eq!(f64_to_words_prec(0.3, 20).unwrap(), str_to_words(&format!("{:.20}", 0.3)).unwrap());
+    assert_eq!(f64_to_words_prec(1.005, 3).unwrap(), "one point zero zero five");
+    assert_eq!(f64_to_words_prec(-0.001, 2).unwrap(), "zero point zero zero");
+    assert_eq!(f64_to_words_prec(-0.0, 0).unwrap(), "zero");
+    assert_eq!(f64_to_words_prec(-0.006, 2).unwrap(), "negative zero point zero one");
+    assert_eq!(f64_to_words_prec(0.0, 0).unwrap(), "zero");
+    assert_eq!(f64_to_words_prec(1e3, 0).unwrap(), "one thousand");
+    assert_eq!(f64_to_words_prec(f64::NAN, 2), Err(FloatConversionError::NotFinite));
+    assert_eq!(f64_to_words_prec(f64::NEG_INFINITY, 2), Err(FloatConversionError::NotFinite));
+    assert_eq!(f64_to_words_prec(1e40, 0), Err(FloatConversionError::TooLarge));
+
+    let tiny = f64::from_bits(1);
+    let words = f64_to_words_prec(tiny, usize::MAX).unwrap();
+    assert_eq!(words, f64_to_words_prec(tiny, 1074).unwrap());
+    assert_eq!(words.split(' ').count(), 1 + 1 + 1074);
+    assert!(words.ends_with("six two five"));
+
+    let trim = WordsOptions::new().trim_fractional_zeros(true);
+    assert_eq!(trim.f64_to_words_prec(2.5, 4).unwrap(), "two point five");
+    assert_eq!(trim.f64_to_words_prec(2.0, 4).unwrap(), "two");
+}
+
+#[test]
+fn seconds_as_duration_words() {
+    assert_eq!(seconds_to_duration_words(1), "one second");
+    assert_eq!(seconds_to_duration_words(60), "one minute");
+    assert_eq!(seconds_to_duration_words(3600 + 2), "one hour two seconds");
+    assert_eq!(seconds_to_duration_words(2 * 3600 + 59 * 60 + 59), "two hours fifty-nine minutes fifty-nine seconds");
+    assert_eq!(seconds_to_duration_words(1000 * 3600 + 61), "one thousand hours one minute one second");
+}
+
+#[test]
+fn integer_and_fraction_styles() {
+    let options = WordsOptions::new().british(true).integer_style(IntegerStyle::Commas);
+    assert_eq!(
+        options.str_to_words("1,234.56").unwrap(),
+        "one thousand, two hundred and thirty-four point five six"
+    );
+    assert_eq!(options.str_to_words("1005").unwrap(), "one thousand and five");
+    assert_eq!(options.str_to_words("-1000000.5").unwrap(), "negative one million point five");
+    assert_eq!(options.u128_to_ord_words(3_000_021), "three million and twenty-first");
+    assert_eq!(options.u128_to_ord_words(3_000_121), "three million, one hundred and twenty-first");
+
+    let options = options.fraction_style(FractionStyle::Denominator);
+    assert_eq!(
+        options.str_to_words("1,234.56").unwrap(),
+        "one thousand, two hundred and thirty-four and fifty-six hundredths"
+    );
+    assert_eq!(options.str_to_words(".125").unwrap(), "one hundred and twenty-five thousandths");
+
+    let options = WordsOptions::new().fraction_style(FractionStyle::Denominator);
+    for (input, expected) in get_inputs_and_expected_outputs("str_nums_decimal_denominator.csv") {
+        assert_eq!(options.str_to_words(&input).unwrap(), expected, "input: {}", input);
+    }
+}